@@ -0,0 +1,45 @@
+use std::io;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, BorderType, Borders, Paragraph};
+
+use tui_confirm_dialog::{run_confirm, ConfirmDialog, ConfirmDialogState};
+
+fn main() -> io::Result<()> {
+    ratatui::run(|terminal| {
+        let mut status = "Press `p` to open the dialog, `q` to quit".to_string();
+        loop {
+            terminal.draw(|f| {
+                let block = Block::default().title(" Demo ").borders(Borders::ALL);
+                let paragraph = Paragraph::new(status.as_str())
+                    .alignment(Alignment::Center)
+                    .block(block);
+                f.render_widget(paragraph, f.area());
+            })?;
+
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Char('q') => break Ok(()),
+                    KeyCode::Char('p') => {
+                        let mut state = ConfirmDialogState::new(
+                            1,
+                            " Please Select ",
+                            "Are you sure you want to delete all files?",
+                        );
+                        let dialog = ConfirmDialog::default()
+                            .borders(Borders::ALL)
+                            .border_type(BorderType::Rounded)
+                            .selected_button_style(Style::default().yellow().underlined());
+                        let outcome = run_confirm(terminal, &mut state, dialog)?;
+                        status = format!("Dialog closed with: {outcome:?}");
+                    }
+                    _ => {}
+                }
+            }
+        }
+    })
+}
@@ -7,7 +7,7 @@ use ratatui::crossterm::{
 use ratatui::text::{Line, Span};
 use ratatui::{prelude::*, widgets::*};
 
-use tui_confirm_dialog::{ButtonLabel, ConfirmDialog, ConfirmDialogState, Listener};
+use tui_confirm_dialog::{clipboard, ButtonLabel, ConfirmDialog, ConfirmDialogState, Listener};
 
 struct App {
     close_status: Option<String>,
@@ -54,6 +54,10 @@ fn run_app(mut app: App) -> io::Result<()> {
 
             if let Event::Key(key) = event::read()? {
                 if app.confirm_popup.is_opened() && app.confirm_popup.handle(&key) {
+                    if let Some(text) = app.confirm_popup.take_copy_request() {
+                        let copied = clipboard::copy_to_terminal(&text).is_ok();
+                        app.confirm_popup.with_copied(copied);
+                    }
                     continue;
                 }
 
@@ -72,8 +76,9 @@ fn run_app(mut app: App) -> io::Result<()> {
                                     Line::from("This action cannot be undone."),
                                 ]))
                                 .with_yes_button(ButtonLabel::from("(Y)es").unwrap())
-                                .with_no_button(ButtonLabel::NO.clone())
+                                .with_no_button(ButtonLabel::from("(N)o").unwrap())
                                 .with_yes_button_selected(false)
+                                .with_copy_key(Some(clipboard::COPY_KEY))
                                 .with_listener(Some(app.popup_tx.clone()));
                             app.confirm_popup.open();
                         }
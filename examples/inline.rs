@@ -0,0 +1,30 @@
+use std::error::Error;
+
+use ratatui::style::Style;
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{BorderType, Borders};
+
+use tui_confirm_dialog::{confirm_inline, ConfirmDialog, ConfirmDialogState, DialogOutcome};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    println!("Cleaning the build directory...");
+    println!("Found 1204 files (312 MB)");
+
+    let mut state = ConfirmDialogState::default();
+    state
+        .with_title(Span::styled(" Cleanup ", Style::new().bold().cyan()))
+        .with_text(Text::from(vec![Line::from("Delete all the files?")]));
+
+    let dialog = ConfirmDialog::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .selected_button_style(Style::default().yellow().underlined().bold());
+
+    match confirm_inline(&mut state, dialog)? {
+        DialogOutcome::Yes => println!("Files deleted"),
+        DialogOutcome::No => println!("Nothing was deleted"),
+        DialogOutcome::Cancelled => println!("Cancelled"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use ratatui::buffer::Buffer;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, Paragraph, StatefulWidget, Widget};
+use tui_confirm_dialog::helper::centered_rect_with_size;
+use tui_confirm_dialog::Theme;
+
+/// A single line text field with a prompt
+pub struct InputDialog<'a> {
+    title: &'a str,
+    prompt: &'a str,
+    theme: &'a Theme,
+}
+
+pub struct InputDialogState {
+    value: Vec<char>,
+    cursor: usize,
+    password: bool,
+}
+
+impl<'a> InputDialog<'a> {
+    pub fn new(title: &'a str, prompt: &'a str, theme: &'a Theme) -> Self {
+        InputDialog {
+            title,
+            prompt,
+            theme,
+        }
+    }
+}
+
+impl InputDialogState {
+    pub fn new(value: String, password: bool) -> Self {
+        let value: Vec<char> = value.chars().collect();
+        InputDialogState {
+            cursor: value.len(),
+            value,
+            password,
+        }
+    }
+
+    /// Handle a key event, returns `Some(Some(value))` when submitted and `Some(None)` when cancelled
+    pub fn handle(&mut self, event: &KeyEvent) -> Option<Option<String>> {
+        if event.kind != KeyEventKind::Press {
+            return None;
+        }
+
+        match event.code {
+            KeyCode::Enter => return Some(Some(self.value.iter().collect())),
+            KeyCode::Esc => return Some(None),
+            KeyCode::Char('c') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Some(None)
+            }
+            // other Ctrl and Alt chords are not text, Ctrl+Alt is AltGr on Windows
+            KeyCode::Char(_) if is_chord(event.modifiers) => {}
+            KeyCode::Char(chr) => {
+                self.value.insert(self.cursor, chr);
+                self.cursor += 1;
+            }
+            KeyCode::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.value.remove(self.cursor);
+            }
+            KeyCode::Delete if self.cursor < self.value.len() => {
+                self.value.remove(self.cursor);
+            }
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.value.len()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.value.len(),
+            _ => {}
+        }
+        None
+    }
+
+    fn display_chars(&self) -> Vec<char> {
+        if self.password {
+            vec!['*'; self.value.len()]
+        } else {
+            self.value.clone()
+        }
+    }
+}
+
+fn is_chord(modifiers: KeyModifiers) -> bool {
+    let chord = modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT);
+    chord == KeyModifiers::CONTROL || chord == KeyModifiers::ALT
+}
+
+impl StatefulWidget for InputDialog<'_> {
+    type State = InputDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let padding = self.theme.padding;
+        let horizontal_padding = padding.left + padding.right;
+        let field_width =
+            (Line::from(state.display_chars().iter().collect::<String>()).width() as u16 + 1)
+                .max(30);
+        let width =
+            (Line::from(self.prompt).width() as u16).max(field_width) + horizontal_padding + 2;
+        let height = padding.top + padding.bottom + 4;
+
+        let area = centered_rect_with_size(width, height, area);
+        let block = Block::bordered()
+            .title(self.title)
+            .title_alignment(self.theme.title_alignment)
+            .borders(self.theme.borders)
+            .border_type(self.theme.border_type)
+            .border_style(self.theme.border_style)
+            .padding(padding)
+            .bg(self.theme.bg);
+        let inner = block.inner(area);
+
+        Clear.render(area, buf);
+        block.render(area, buf);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Length(1)])
+            .split(inner);
+
+        Paragraph::new(self.prompt)
+            .style(self.theme.text_style)
+            .render(rows[0], buf);
+
+        // keep the cursor visible when the value is wider than the field
+        let chars = state.display_chars();
+        let visible = rows[1].width.saturating_sub(1) as usize;
+        let start = state.cursor.saturating_sub(visible);
+        let before: String = chars[start..state.cursor].iter().collect();
+        let at = chars.get(state.cursor).copied().unwrap_or(' ').to_string();
+        let after: String = chars.iter().skip(state.cursor + 1).collect();
+
+        Paragraph::new(Line::from(vec![
+            Span::raw(before),
+            Span::styled(at, Style::new().reversed()),
+            Span::raw(after),
+        ]))
+        .style(self.theme.selected_button_style)
+        .render(rows[1], buf);
+    }
+}
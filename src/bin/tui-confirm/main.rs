@@ -0,0 +1,444 @@
+//! `tui-confirm` shows a single dialog on the controlling terminal and reports the result
+//! through the exit code and stdout, so it can be used from shell scripts:
+//!
+//! ```sh
+//! if tui-confirm confirm "Delete all files?"; then rm -rf ./build; fi
+//! name=$(tui-confirm input "Project name" --default demo)
+//! ```
+//!
+//! Exit codes: `0` yes / ok, `1` no, `255` cancelled with Esc or Ctrl+C, `2` usage or terminal
+//! errors.
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use ratatui::backend::CrosstermBackend;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use ratatui::crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::crossterm::{cursor, execute};
+use ratatui::text::Text;
+use ratatui::Terminal;
+use tui_confirm_dialog::{
+    clipboard, run_confirm, ButtonLabel, ButtonRole, ConfirmDialog, ConfirmDialogState,
+    DialogOutcome, Key, PopupMessage, Severities, Severity, Theme, Translations,
+};
+
+use crate::input::{InputDialog, InputDialogState};
+use crate::select::{SelectDialog, SelectDialogState};
+
+mod input;
+mod select;
+
+const EXIT_YES: u8 = 0;
+const EXIT_NO: u8 = 1;
+const EXIT_ERROR: u8 = 2;
+const EXIT_CANCEL: u8 = 255;
+
+/// Aborts every dialog, as `Ctrl+C` does in any other command
+const ABORT_KEY: Key = Key::Ctrl('c');
+/// Copies the text of a confirm dialog or message, `Ctrl+C` being taken by [`ABORT_KEY`]
+const COPY_KEY: Key = Key::Ctrl('y');
+
+/// How long `Enter` and the mnemonics are ignored after a delayed dialog opens
+const INPUT_GUARD: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "tui-confirm",
+    version,
+    about = "Show terminal dialogs from shell scripts"
+)]
+struct Cli {
+    /// Dialog title
+    #[arg(long, short, global = true, default_value = "")]
+    title: String,
+
+    /// Print the result as a JSON object
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Load the dialog theme from a TOML or JSON file
+    #[arg(long, global = true, value_name = "FILE")]
+    theme: Option<PathBuf>,
+
+    /// Dialog severity, picks the border color and the title icon
+    #[arg(long, global = true, value_enum)]
+    severity: Option<SeverityArg>,
+
+    /// Language of the default buttons, e.g. "de" or "fr_FR", defaults to $LC_ALL / $LANG
+    #[arg(long, global = true)]
+    locale: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Ask a yes/no question
+    Confirm {
+        /// The question, newlines split it into several lines
+        text: String,
+
+        /// Label of the affirmative button, e.g. "(D)elete"
+        #[arg(long)]
+        yes: Option<String>,
+
+        /// Label of the negative button, e.g. "(K)eep"
+        #[arg(long)]
+        no: Option<String>,
+
+        /// Only show the affirmative button
+        #[arg(long)]
+        no_cancel_button: bool,
+
+        /// Select the negative button by default
+        #[arg(long)]
+        default_no: bool,
+
+        /// Ignore Esc
+        #[arg(long)]
+        modal: bool,
+
+        /// Style the affirmative button as destructive
+        #[arg(long)]
+        destructive: bool,
+
+        /// Keep the affirmative button disabled for this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        delay: Option<u64>,
+    },
+
+    /// Show a message and wait for a key press
+    Message {
+        /// The message, newlines split it into several lines
+        text: String,
+    },
+
+    /// Ask for a line of text, printed on stdout
+    Input {
+        /// The prompt shown above the input field
+        prompt: String,
+
+        /// Initial value of the input field
+        #[arg(long, default_value = "")]
+        default: String,
+
+        /// Mask the typed characters
+        #[arg(long)]
+        password: bool,
+    },
+
+    /// Pick one item of a list, printed on stdout
+    Select {
+        /// The prompt shown above the list
+        prompt: String,
+
+        /// The items to choose from
+        #[arg(required = true)]
+        items: Vec<String>,
+
+        /// Index of the initially selected item
+        #[arg(long, default_value_t = 0)]
+        default: usize,
+    },
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum SeverityArg {
+    Info,
+    Warning,
+    Error,
+    Success,
+    Question,
+}
+
+impl From<SeverityArg> for Severity {
+    fn from(value: SeverityArg) -> Self {
+        match value {
+            SeverityArg::Info => Severity::Info,
+            SeverityArg::Warning => Severity::Warning,
+            SeverityArg::Error => Severity::Error,
+            SeverityArg::Success => Severity::Success,
+            SeverityArg::Question => Severity::Question,
+        }
+    }
+}
+
+/// The result of a dialog
+#[derive(Debug)]
+enum Outcome {
+    Yes,
+    No,
+    Ok,
+    Cancel,
+    Value(String),
+    Selected(usize, String),
+}
+
+impl Outcome {
+    fn exit_code(&self) -> u8 {
+        match self {
+            Outcome::Yes | Outcome::Ok | Outcome::Value(_) | Outcome::Selected(..) => EXIT_YES,
+            Outcome::No => EXIT_NO,
+            Outcome::Cancel => EXIT_CANCEL,
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        match self {
+            Outcome::Yes => serde_json::json!({ "result": "yes", "answer": true }),
+            Outcome::No => serde_json::json!({ "result": "no", "answer": false }),
+            Outcome::Ok => serde_json::json!({ "result": "ok" }),
+            Outcome::Cancel => serde_json::json!({ "result": "cancel" }),
+            Outcome::Value(value) => serde_json::json!({ "result": "ok", "value": value }),
+            Outcome::Selected(index, value) => {
+                serde_json::json!({ "result": "ok", "index": index, "value": value })
+            }
+        }
+    }
+
+    fn to_plain(&self) -> Option<String> {
+        match self {
+            Outcome::Yes => Some("yes".to_string()),
+            Outcome::No => Some("no".to_string()),
+            Outcome::Ok | Outcome::Cancel => None,
+            Outcome::Value(value) => Some(value.clone()),
+            Outcome::Selected(_, value) => Some(value.clone()),
+        }
+    }
+}
+
+type TtyTerminal = Terminal<CrosstermBackend<File>>;
+
+/// Puts /dev/tty in raw mode on the alternate screen and restores it when dropped,
+/// so stdout stays free for the result even inside `$(...)`
+struct TtyGuard {
+    terminal: TtyTerminal,
+    _raw_mode: RawMode,
+}
+
+impl TtyGuard {
+    fn new() -> io::Result<Self> {
+        let tty = File::options().read(true).write(true).open("/dev/tty")?;
+        let raw_mode = RawMode::enable()?;
+        let mut guard = TtyGuard {
+            terminal: Terminal::new(CrosstermBackend::new(tty))?,
+            _raw_mode: raw_mode,
+        };
+        // the guard restores the screen if this fails half way
+        execute!(
+            guard.terminal.backend_mut(),
+            EnterAlternateScreen,
+            cursor::Hide
+        )?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TtyGuard {
+    fn drop(&mut self) {
+        let _ = execute!(
+            self.terminal.backend_mut(),
+            LeaveAlternateScreen,
+            cursor::Show
+        );
+    }
+}
+
+/// Leaves raw mode when dropped, so an error while setting up the screen does not leave the
+/// shell in raw mode
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        enable_raw_mode()?;
+        Ok(RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let translations = match &cli.locale {
+        Some(locale) => Translations::for_locale(locale),
+        None => Translations::from_env(),
+    };
+
+    let mut theme = match cli.theme.as_ref().map(Theme::from_file).transpose() {
+        Ok(theme) => theme.unwrap_or_default(),
+        Err(err) => {
+            eprintln!("tui-confirm: {err}");
+            return ExitCode::from(EXIT_ERROR);
+        }
+    };
+    // translate the severity buttons unless the theme sets its own
+    if theme.severities == Severities::default() {
+        theme.severities = Severities::localized(&translations);
+    }
+
+    let outcome =
+        TtyGuard::new().and_then(|mut guard| run(&cli, &theme, &translations, &mut guard.terminal));
+
+    match outcome {
+        Ok(outcome) => {
+            let output = if cli.json {
+                Some(outcome.to_json().to_string())
+            } else {
+                outcome.to_plain()
+            };
+            if let Some(output) = output {
+                let mut stdout = io::stdout().lock();
+                let _ = writeln!(stdout, "{output}");
+            }
+            ExitCode::from(outcome.exit_code())
+        }
+        Err(err) => {
+            eprintln!("tui-confirm: {err}");
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+fn run(
+    cli: &Cli,
+    theme: &Theme,
+    translations: &Translations,
+    terminal: &mut TtyTerminal,
+) -> io::Result<Outcome> {
+    let severity = cli.severity.map(Severity::from);
+
+    match &cli.command {
+        Command::Confirm {
+            text,
+            yes,
+            no,
+            no_cancel_button,
+            default_no,
+            modal,
+            destructive,
+            delay,
+        } => {
+            let mut state = ConfirmDialogState::new(0, cli.title.clone(), Text::from(text.clone()));
+            state
+                .with_translations(translations)
+                .with_copy_key(Some(COPY_KEY));
+            if let Some(severity) = severity {
+                state.with_themed_severity(severity, theme);
+            }
+            if let Some(yes) = yes {
+                state.with_yes_button(parse_label(yes)?);
+            }
+            if let Some(no) = no {
+                state.with_no_button(parse_label(no)?);
+            }
+            if *no_cancel_button {
+                state.without_no_button();
+            }
+            if *destructive {
+                state.with_yes_role(ButtonRole::Destructive);
+            }
+            if let Some(delay) = delay {
+                state
+                    .with_enable_delay(Duration::from_secs(*delay))
+                    .with_input_guard(INPUT_GUARD);
+            }
+            if let Some(conflict) = state.mnemonic_conflict() {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, conflict));
+            }
+            state.modal(*modal).with_yes_button_selected(!default_no);
+
+            let dialog = ConfirmDialog::default().theme(theme);
+            Ok(match run_confirm(terminal, &mut state, dialog)? {
+                DialogOutcome::Yes => Outcome::Yes,
+                DialogOutcome::No => Outcome::No,
+                DialogOutcome::Cancelled => Outcome::Cancel,
+            })
+        }
+
+        Command::Message { text } => {
+            let popup = || {
+                let popup =
+                    PopupMessage::new(cli.title.as_str(), Text::from(text.as_str())).theme(theme);
+                match severity {
+                    Some(severity) => popup.severity(severity),
+                    None => popup,
+                }
+            };
+            let mut copied = false;
+            loop {
+                terminal.draw(|f| f.render_widget(popup().copied(copied), f.area()))?;
+                if let Event::Key(key) = event::read()? {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    if ABORT_KEY.matches(&key) {
+                        return Ok(Outcome::Cancel);
+                    }
+                    if COPY_KEY.matches(&key) {
+                        copied = clipboard::copy_to_terminal(&popup().plain_text()).is_ok();
+                    } else {
+                        break;
+                    }
+                }
+            }
+            Ok(Outcome::Ok)
+        }
+
+        Command::Input {
+            prompt,
+            default,
+            password,
+        } => {
+            let mut state = InputDialogState::new(default.clone(), *password);
+            loop {
+                terminal.draw(|f| {
+                    let dialog = InputDialog::new(cli.title.as_str(), prompt.as_str(), theme);
+                    f.render_stateful_widget(dialog, f.area(), &mut state)
+                })?;
+                if let Event::Key(key) = event::read()? {
+                    if let Some(result) = state.handle(&key) {
+                        return Ok(result.map(Outcome::Value).unwrap_or(Outcome::Cancel));
+                    }
+                }
+            }
+        }
+
+        Command::Select {
+            prompt,
+            items,
+            default,
+        } => {
+            let mut state = SelectDialogState::new(items.len(), *default);
+            loop {
+                terminal.draw(|f| {
+                    let dialog =
+                        SelectDialog::new(cli.title.as_str(), prompt.as_str(), items, theme);
+                    f.render_stateful_widget(dialog, f.area(), &mut state)
+                })?;
+                if let Event::Key(key) = event::read()? {
+                    if let Some(result) = state.handle(&key) {
+                        return Ok(result
+                            .map(|index| Outcome::Selected(index, items[index].clone()))
+                            .unwrap_or(Outcome::Cancel));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn parse_label(value: &str) -> io::Result<ButtonLabel> {
+    ButtonLabel::from(value).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))
+}
@@ -0,0 +1,115 @@
+use ratatui::buffer::Buffer;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Clear, List, ListState, Paragraph, StatefulWidget, Widget};
+use tui_confirm_dialog::helper::centered_rect_with_size;
+use tui_confirm_dialog::Theme;
+
+/// A prompt followed by a list of items to pick from
+pub struct SelectDialog<'a> {
+    title: &'a str,
+    prompt: &'a str,
+    items: &'a [String],
+    theme: &'a Theme,
+}
+
+pub struct SelectDialogState {
+    len: usize,
+    list: ListState,
+}
+
+impl<'a> SelectDialog<'a> {
+    pub fn new(title: &'a str, prompt: &'a str, items: &'a [String], theme: &'a Theme) -> Self {
+        SelectDialog {
+            title,
+            prompt,
+            items,
+            theme,
+        }
+    }
+}
+
+impl SelectDialogState {
+    pub fn new(len: usize, selected: usize) -> Self {
+        SelectDialogState {
+            len,
+            list: ListState::default().with_selected(Some(selected.min(len.saturating_sub(1)))),
+        }
+    }
+
+    /// Handle a key event, returns `Some(Some(index))` when an item is picked and `Some(None)` when cancelled
+    pub fn handle(&mut self, event: &KeyEvent) -> Option<Option<usize>> {
+        if event.kind != KeyEventKind::Press {
+            return None;
+        }
+
+        let selected = self.list.selected().unwrap_or(0);
+        match event.code {
+            KeyCode::Enter => return Some(Some(selected)),
+            KeyCode::Esc => return Some(None),
+            KeyCode::Char('c') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Some(None)
+            }
+            KeyCode::Up => self.list.select(Some(selected.saturating_sub(1))),
+            KeyCode::Down => self
+                .list
+                .select(Some((selected + 1).min(self.len.saturating_sub(1)))),
+            KeyCode::Home => self.list.select(Some(0)),
+            KeyCode::End => self.list.select(Some(self.len.saturating_sub(1))),
+            _ => {}
+        }
+        None
+    }
+}
+
+impl StatefulWidget for SelectDialog<'_> {
+    type State = SelectDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let padding = self.theme.padding;
+        let items_width = self
+            .items
+            .iter()
+            .map(|item| Line::from(item.as_str()).width() + 2)
+            .max()
+            .unwrap_or_default() as u16;
+        let width = (Line::from(self.prompt).width() as u16)
+            .max(items_width)
+            .max(30)
+            + padding.left
+            + padding.right
+            + 2;
+        let height = self.items.len() as u16 + padding.top + padding.bottom + 4;
+
+        let area = centered_rect_with_size(width, height, area);
+        let block = Block::bordered()
+            .title(self.title)
+            .title_alignment(self.theme.title_alignment)
+            .borders(self.theme.borders)
+            .border_type(self.theme.border_type)
+            .border_style(self.theme.border_style)
+            .padding(padding)
+            .bg(self.theme.bg);
+        let inner = block.inner(area);
+
+        Clear.render(area, buf);
+        block.render(area, buf);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(1)])
+            .split(inner);
+
+        Paragraph::new(self.prompt)
+            .style(self.theme.text_style)
+            .render(rows[0], buf);
+
+        let list = List::new(self.items.iter().map(String::as_str))
+            .style(self.theme.button_style)
+            .highlight_style(self.theme.selected_button_style)
+            .highlight_symbol("> ");
+        StatefulWidget::render(list, rows[1], buf, &mut state.list);
+    }
+}
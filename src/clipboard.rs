@@ -0,0 +1,156 @@
+//! Copy text to the system clipboard with the OSC 52 escape sequence, which most terminal
+//! emulators support, also over SSH.
+//!
+//! ```
+//! use tui_confirm_dialog::clipboard::osc52;
+//!
+//! assert_eq!("\x1b]52;c;aGk=\x07", osc52("hi"));
+//! ```
+use std::io::{self, Write};
+
+#[cfg(feature = "crossterm")]
+use ratatui::crossterm::event::{KeyEvent, KeyEventKind};
+use ratatui_core::text::{Line, Text};
+
+use crate::{ConfirmDialogState, Key, PopupMessage};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The message shown once the text was copied
+pub(crate) const COPIED: &str = " ✓ Copied to clipboard ";
+
+/// The escape sequence setting the clipboard to `text`
+pub fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+/// Write the escape sequence setting the clipboard to `text` to `writer`
+pub fn copy<W>(writer: &mut W, text: &str) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_all(osc52(text).as_bytes())?;
+    writer.flush()
+}
+
+/// Copy `text` through the controlling terminal, so it works even when stdout is redirected.
+/// Falls back to stdout where there is no `/dev/tty`.
+pub fn copy_to_terminal(text: &str) -> io::Result<()> {
+    #[cfg(unix)]
+    if let Ok(mut tty) = std::fs::OpenOptions::new().write(true).open("/dev/tty") {
+        return copy(&mut tty, text);
+    }
+    copy(&mut io::stdout(), text)
+}
+
+/// The usual copy key, for [`ConfirmDialogState::with_copy_key`]
+pub const COPY_KEY: Key = Key::Ctrl('c');
+
+/// Check if `event` is a press of the usual copy key, [`COPY_KEY`]
+#[cfg(feature = "crossterm")]
+pub fn is_copy_key(event: &KeyEvent) -> bool {
+    event.kind == KeyEventKind::Press && COPY_KEY.matches(event)
+}
+
+impl ConfirmDialogState {
+    /// The title, text and details of the dialog without styles, as copied with the copy key
+    pub fn plain_text(&self) -> String {
+        let mut parts = vec![
+            plain_line(&self.title).trim().to_string(),
+            plain_text(&self.text),
+        ];
+        parts.extend(self.details.as_ref().map(plain_text));
+        join(parts)
+    }
+
+    /// Let `key` copy the plain text, e.g. [`COPY_KEY`]. Copying is off by default since the
+    /// event loop has to write the text, see [`ConfirmDialogState::take_copy_request`].
+    pub fn with_copy_key(&mut self, key: Option<Key>) -> &mut Self {
+        self.copy_key = key;
+        self
+    }
+
+    /// Show that the text was copied, until the next key
+    pub fn with_copied(&mut self, copied: bool) -> &mut Self {
+        self.copied = copied;
+        self
+    }
+
+    /// Whether the text was reported as copied with [`ConfirmDialogState::with_copied`]
+    pub fn is_copied(&self) -> bool {
+        self.copied
+    }
+
+    /// The text to copy after the copy key, to be written with [`copy_to_terminal`] by the
+    /// event loop, which then calls [`ConfirmDialogState::with_copied`] on success.
+    /// `run_confirm` and `confirm_inline` already do it.
+    pub fn take_copy_request(&mut self) -> Option<String> {
+        self.copy_request.take()
+    }
+
+    /// Ask the event loop to copy the plain text
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn request_copy(&mut self) {
+        self.copy_request = Some(self.plain_text());
+    }
+}
+
+impl PopupMessage<'_, '_> {
+    /// The title and message without styles, for [`copy_to_terminal`]
+    pub fn plain_text(&self) -> String {
+        join(vec![
+            plain_line(&self.title).trim().to_string(),
+            plain_text(&self.message),
+        ])
+    }
+
+    /// Show that the message was copied to the clipboard
+    pub fn copied(mut self, copied: bool) -> Self {
+        self.copied = copied;
+        self
+    }
+}
+
+fn plain_line(line: &Line<'_>) -> String {
+    line.spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect()
+}
+
+fn plain_text(text: &Text<'_>) -> String {
+    text.lines
+        .iter()
+        .map(plain_line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Join the non-empty parts with a blank line
+fn join(parts: Vec<String>) -> String {
+    parts
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn base64(bytes: &[u8]) -> String {
+    let mut output = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let value = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |value, (index, &byte)| {
+                value | u32::from(byte) << (16 - 8 * index)
+            });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                output.push(BASE64[(value >> (18 - 6 * index) & 0x3f) as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
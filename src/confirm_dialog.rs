@@ -1,38 +1,59 @@
 use core::fmt;
+use std::borrow::Cow;
 use std::error::Error;
 use std::fmt::Display;
 use std::sync::mpsc::Sender;
 use std::sync::LazyLock;
+use std::time::{Duration, Instant};
 
 use rand::random;
 #[cfg(feature = "crossterm")]
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::{Alignment, Constraint, Direction, Layout, Rect};
+use ratatui_core::layout::{Alignment, Constraint, Direction, Layout, Rect, Size};
 use ratatui_core::style::{Color, Style, Stylize};
-use ratatui_core::text::{Line, Text};
+use ratatui_core::text::{Line, Span, Text};
 use ratatui_core::widgets::{StatefulWidget, Widget};
 use ratatui_widgets::block::{Block, Padding};
 use ratatui_widgets::borders::{BorderType, Borders};
 use ratatui_widgets::clear::Clear;
 use ratatui_widgets::paragraph::{Paragraph, Wrap};
-use regex::Regex;
-
-use crate::{ButtonLabel, ConfirmDialog, ConfirmDialogState, Listener, TryFromSliceError};
-
-static BUTTON_LABEL_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"(\(\w\))"#).unwrap());
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
+use crate::clipboard::COPIED;
+use crate::details::DEFAULT_DETAILS_HEIGHT;
+use crate::hyperlink;
+#[cfg(feature = "crossterm")]
+use crate::keys::Action;
+use crate::layout::DEFAULT_BUTTON_GAP;
+use crate::{
+    ButtonLabel, ButtonLabelError, ButtonRole, ConfirmDialog, ConfirmDialogState, DialogOutcome,
+    Listener, MnemonicDisplay,
+};
+
+#[allow(clippy::declare_interior_mutable_const)]
 impl ButtonLabel {
     pub const YES: LazyLock<ButtonLabel> = LazyLock::new(|| ButtonLabel {
         label: "(Y)es".to_string(),
         control: 'y',
         style: None,
+        auto_mnemonic: false,
+        role: None,
+        is_default: false,
+        disabled: false,
+        disabled_reason: None,
     });
 
     pub const NO: LazyLock<ButtonLabel> = LazyLock::new(|| ButtonLabel {
         label: "(N)o".to_string(),
         control: 'n',
         style: None,
+        auto_mnemonic: false,
+        role: None,
+        is_default: false,
+        disabled: false,
+        disabled_reason: None,
     });
 
     pub fn new<S>(label: S, control: char) -> Self
@@ -43,63 +64,178 @@ impl ButtonLabel {
             label: label.into(),
             control,
             style: None,
+            auto_mnemonic: false,
+            role: None,
+            is_default: false,
+            disabled: false,
+            disabled_reason: None,
         }
     }
 
-    pub fn from<S>(label: S) -> Result<Self, TryFromSliceError>
+    pub fn from<S>(label: S) -> Result<Self, ButtonLabelError>
     where
         S: Into<String>,
     {
         label.into().as_str().try_into()
     }
 
+    /// The columns the button takes: the display width of the label, mnemonic marker included,
+    /// plus a space on each side. It is never 0, so there is no `is_empty`.
+    #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
-        self.label.len() + 2
+        self.label.width() + 2
     }
 
     pub(crate) fn with_style(mut self, style: Option<Style>) -> Self {
         self.style = style;
         self
     }
+
+    /// The label split around the `(m)` marker of its mnemonic, without the parentheses
+    pub(crate) fn mnemonic_parts(&self) -> Option<(&str, &str, &str)> {
+        let graphemes = self.label.grapheme_indices(true).collect::<Vec<_>>();
+        graphemes
+            .windows(3)
+            .find(|window| {
+                window[0].1 == "("
+                    && window[2].1 == ")"
+                    && mnemonic_char(window[1].1) == Some(self.control)
+            })
+            .map(|window| {
+                (
+                    &self.label[..window[0].0],
+                    window[1].1,
+                    &self.label[window[2].0 + 1..],
+                )
+            })
+    }
 }
 
 impl TryFrom<&str> for ButtonLabel {
-    type Error = TryFromSliceError;
+    type Error = ButtonLabelError;
 
+    /// Parse a label such as `"(Y)es"` or `"&Yes"`, where the parenthesized character or the one
+    /// after `&` is the mnemonic, and `&&` is a literal `&`. Without a marker, the first letter or
+    /// digit becomes the mnemonic: `"No"` gives `"(N)o"`.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         if value.is_empty() {
-            return Err(TryFromSliceError);
+            return Err(ButtonLabelError::Empty);
+        }
+        if value.trim().is_empty() {
+            return Err(ButtonLabelError::WhitespaceOnly);
         }
 
-        if let Some(result) = BUTTON_LABEL_RE.find(value) {
-            let control_char = value.chars().nth(result.start() + 1).unwrap();
-            Ok(ButtonLabel {
-                label: value.to_string(),
-                control: control_char.to_ascii_lowercase(),
-                style: None,
-            })
-        } else {
-            let char = value.chars().nth(0).unwrap();
-            let label = format!("({char}){}", &value[1..]);
+        let label = ampersand_to_parentheses(value)?;
+        let graphemes = label.grapheme_indices(true).collect::<Vec<_>>();
+        let markers = graphemes
+            .windows(3)
+            .filter(|window| window[0].1 == "(" && window[2].1 == ")")
+            .map(|window| window[1].1)
+            .collect::<Vec<_>>();
+
+        let (label, mnemonic, auto_mnemonic) = match markers.as_slice() {
+            [mnemonic] if is_mnemonic(mnemonic) => (label.clone(), *mnemonic, false),
+            [mnemonic] => {
+                return Err(ButtonLabelError::InvalidMnemonic {
+                    label: value.to_string(),
+                    mnemonic: mnemonic.to_string(),
+                })
+            }
+            [_, _, ..] => {
+                return Err(ButtonLabelError::MultipleMnemonics {
+                    label: value.to_string(),
+                })
+            }
+            [] => {
+                let (index, first) = graphemes
+                    .iter()
+                    .find(|(_, grapheme)| is_mnemonic(grapheme))
+                    .copied()
+                    .ok_or_else(|| ButtonLabelError::InvalidMnemonic {
+                        label: value.to_string(),
+                        mnemonic: graphemes
+                            .iter()
+                            .find(|(_, grapheme)| !grapheme.trim().is_empty())
+                            .map_or_else(String::new, |(_, grapheme)| grapheme.to_string()),
+                    })?;
+                let label = format!(
+                    "{}({first}){}",
+                    &label[..index],
+                    &label[index + first.len()..]
+                );
+                (label, first, true)
+            }
+        };
 
-            Ok(ButtonLabel {
-                label,
-                control: char.to_ascii_lowercase(),
-                style: None,
-            })
+        Ok(ButtonLabel {
+            control: mnemonic_char(mnemonic).ok_or(ButtonLabelError::WhitespaceOnly)?,
+            label,
+            style: None,
+            auto_mnemonic,
+            role: None,
+            is_default: false,
+            disabled: false,
+            disabled_reason: None,
+        })
+    }
+}
+
+/// Rewrite the `&Yes` syntax as `(Y)es`. `&&` is a literal `&`, as is a `&` followed by
+/// whitespace or ending the label.
+fn ampersand_to_parentheses(value: &str) -> Result<String, ButtonLabelError> {
+    let mut output = String::with_capacity(value.len() + 2);
+    let mut marked = false;
+    let mut graphemes = value.graphemes(true).peekable();
+
+    while let Some(grapheme) = graphemes.next() {
+        if grapheme == "&" {
+            match graphemes.peek() {
+                Some(&"&") => {
+                    graphemes.next();
+                }
+                Some(next) if is_mnemonic(next) => {
+                    if marked {
+                        return Err(ButtonLabelError::MultipleMnemonics {
+                            label: value.to_string(),
+                        });
+                    }
+                    output.push_str(&format!("({next})"));
+                    graphemes.next();
+                    marked = true;
+                    continue;
+                }
+                Some(next) if !next.trim().is_empty() => {
+                    return Err(ButtonLabelError::InvalidMnemonic {
+                        label: value.to_string(),
+                        mnemonic: next.to_string(),
+                    });
+                }
+                _ => {}
+            }
         }
+        output.push_str(grapheme);
     }
+    Ok(output)
+}
+
+/// A grapheme can be a mnemonic when it starts with a letter or a digit
+pub(crate) fn is_mnemonic(grapheme: &str) -> bool {
+    grapheme.chars().next().is_some_and(char::is_alphanumeric)
 }
 
-impl<'a> Into<Text<'a>> for ButtonLabel {
-    fn into(self) -> Text<'a> {
-        Text::styled(self.label, self.style.unwrap_or(Style::default()))
+/// The key matching `grapheme`: its first character, lowercased
+pub(crate) fn mnemonic_char(grapheme: &str) -> Option<char> {
+    grapheme.chars().next()?.to_lowercase().next()
+}
+
+impl From<ButtonLabel> for Text<'_> {
+    fn from(value: ButtonLabel) -> Self {
+        Text::styled(value.label, value.style.unwrap_or_default())
     }
 }
 
 impl PartialEq for ButtonLabel {
     fn eq(&self, other: &Self) -> bool {
-        eprintln!("self: {:?}, other: {:?}", self, other);
         self.control == other.control && self.label == other.label
     }
 }
@@ -110,9 +246,6 @@ impl ConfirmDialogState {
         T: Into<Line<'static>>,
         R: Into<Text<'static>>,
     {
-        let yes_button = ButtonLabel::new("Yes", 'y');
-        let no_button = ButtonLabel::new("No", 'n');
-
         ConfirmDialogState {
             id,
             title: title.into(),
@@ -121,18 +254,50 @@ impl ConfirmDialogState {
             opened: false,
             listener: None,
             yes_selected: true,
-            yes_button,
-            no_button: Some(no_button),
+            yes_button: ButtonLabel::new("Yes", 'y'),
+            no_button: Some(ButtonLabel::new("No", 'n')),
+            severity: None,
+            dont_ask_again: None,
+            dont_ask_again_checked: false,
+            decision_store: None,
+            store_error: None,
+            outcome: None,
+            button_order: Default::default(),
+            triggered_disabled: None,
+            enable_delay: Duration::ZERO,
+            input_guard: Duration::ZERO,
+            opened_at: None,
+            details: None,
+            details_expanded: false,
+            details_scroll: 0,
+            details_viewport: 0,
+            copy_key: None,
+            copied: false,
+            copy_request: None,
+            hyperlinks: Vec::new(),
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn as_mut(&mut self) -> &mut Self {
         self
     }
 
-    /// Open the dialog
-    pub const fn open(&mut self) {
-        self.opened = true;
+    /// Open the dialog. If a "Don't ask again" decision was remembered for this dialog id,
+    /// the dialog stays closed and the listener immediately receives the remembered answer.
+    pub fn open(&mut self) {
+        self.dont_ask_again_checked = false;
+        self.triggered_disabled = None;
+        self.outcome = None;
+        self.store_error = None;
+        if let Some(answer) = self.remembered_decision() {
+            self.opened = false;
+            self.send_close_message(Some(answer));
+        } else {
+            self.opened_at = Some(Instant::now());
+            self.select_default_button();
+            self.opened = true;
+        }
     }
 
     /// Close the dialog
@@ -176,6 +341,7 @@ impl ConfirmDialogState {
         B: Into<ButtonLabel>,
     {
         self.yes_button = label.into();
+        self.resolve_mnemonics();
         self
     }
 
@@ -185,6 +351,7 @@ impl ConfirmDialogState {
         B: Into<ButtonLabel>,
     {
         self.no_button = Some(label.into());
+        self.resolve_mnemonics();
         self
     }
 
@@ -205,67 +372,54 @@ impl ConfirmDialogState {
         self.opened
     }
 
+    /// How the dialog was last closed, `None` while it is opened or if it was closed with
+    /// [`ConfirmDialogState::close`]
+    pub fn outcome(&self) -> Option<DialogOutcome> {
+        self.outcome
+    }
+
     #[cfg(feature = "crossterm")]
     /// Handle the dialog events
     pub fn handle(&mut self, event: &KeyEvent) -> bool {
         if event.kind == KeyEventKind::Press {
+            // keys typed before the dialog showed up must not answer it
+            if self.is_guarded() && matches!(event.code, KeyCode::Enter | KeyCode::Char(_)) {
+                return true;
+            }
+            self.triggered_disabled = None;
+            self.copied = false;
+            if let Some(action) = self.action(event) {
+                return self.perform(action);
+            }
             match event.code {
-                KeyCode::Esc => {
-                    if !self.modal {
-                        self.opened = false;
-                        self.send_close_message(None);
-                        true
-                    } else {
-                        false
-                    }
+                // a modal dialog cannot be cancelled
+                KeyCode::Esc => false,
+
+                // Ctrl and Alt chords never trigger a mnemonic
+                KeyCode::Char(chr) if !crate::Key::Char(chr).matches(event) => self.modal,
+
+                // an ambiguous mnemonic triggers neither button
+                KeyCode::Char(chr)
+                    if self.mnemonic_conflict().is_some_and(|c| c.mnemonic == chr) =>
+                {
+                    self.modal
                 }
 
                 KeyCode::Char(chr) => {
                     if chr == self.yes_button.control {
-                        self.opened = false;
-                        self.send_close_message(Some(true));
+                        self.press(true);
                         return true;
                     }
 
                     if let Some(no_button) = &self.no_button {
                         if chr == no_button.control {
-                            self.opened = false;
-                            self.send_close_message(Some(false));
+                            self.press(false);
                             return true;
                         }
                     }
                     self.modal
                 }
 
-                KeyCode::Right => {
-                    if let Some(_no_button) = &self.no_button {
-                        if self.yes_selected {
-                            self.yes_selected = false;
-                        }
-                    }
-                    self.modal
-                }
-
-                KeyCode::Left => {
-                    if let Some(_no_button) = &self.no_button {
-                        if !self.yes_selected {
-                            self.yes_selected = true;
-                        }
-                    }
-                    self.modal
-                }
-
-                KeyCode::Enter => {
-                    if self.yes_selected {
-                        self.opened = false;
-                        self.send_close_message(Some(true));
-                    } else {
-                        self.opened = false;
-                        self.send_close_message(Some(false));
-                    }
-                    true
-                }
-
                 _ => self.modal,
             }
         } else {
@@ -273,13 +427,91 @@ impl ConfirmDialogState {
         }
     }
 
-    fn send_close_message(&self, result: Option<bool>) {
+    /// Do what a bound key does
+    #[cfg(feature = "crossterm")]
+    fn perform(&mut self, action: Action) -> bool {
+        match action {
+            Action::Cancel => {
+                let answer = self
+                    .cancel_answer()
+                    .filter(|&answer| self.is_enabled(answer));
+                // a cancel answers the dialog but is never remembered
+                self.opened = false;
+                self.send_close_message(answer);
+            }
+            Action::ToggleCheckbox => {
+                self.dont_ask_again_checked = !self.dont_ask_again_checked;
+            }
+            Action::Copy => self.request_copy(),
+            // the buttons follow the order they were last rendered in
+            Action::SelectFirst(first) => {
+                let yes = self.yes_first() == first;
+                if self.is_enabled(yes) {
+                    self.yes_selected = yes;
+                }
+                return self.modal;
+            }
+            Action::Confirm => self.press(self.yes_selected),
+            Action::ToggleDetails => {
+                self.toggle_details();
+            }
+            Action::Scroll(lines) => {
+                self.scroll_details(lines);
+            }
+            Action::Page(pages) => {
+                self.scroll_details(pages * i32::from(self.details_viewport.max(1)));
+            }
+        }
+        true
+    }
+
+    /// Close with the answer of a button, unless it is disabled
+    #[cfg(feature = "crossterm")]
+    fn press(&mut self, yes: bool) {
+        if self.is_enabled(yes) {
+            self.close_with(Some(yes));
+        } else {
+            self.triggered_disabled = Some(yes);
+        }
+    }
+
+    #[cfg(feature = "crossterm")]
+    fn close_with(&mut self, result: Option<bool>) {
+        self.opened = false;
+        if let Some(answer) = result {
+            self.remember_decision(answer);
+        }
+        self.send_close_message(result);
+    }
+
+    pub(crate) fn send_close_message(&mut self, result: Option<bool>) {
+        self.outcome = Some(result.into());
         if let Some(tx) = self.listener.as_ref() {
             let _ = tx.send((self.id, result));
         }
     }
 }
 
+impl From<Option<bool>> for DialogOutcome {
+    fn from(value: Option<bool>) -> Self {
+        match value {
+            Some(true) => DialogOutcome::Yes,
+            Some(false) => DialogOutcome::No,
+            None => DialogOutcome::Cancelled,
+        }
+    }
+}
+
+impl From<DialogOutcome> for Option<bool> {
+    fn from(value: DialogOutcome) -> Self {
+        match value {
+            DialogOutcome::Yes => Some(true),
+            DialogOutcome::No => Some(false),
+            DialogOutcome::Cancelled => None,
+        }
+    }
+}
+
 impl Default for ConfirmDialog {
     fn default() -> Self {
         ConfirmDialog::new()
@@ -296,6 +528,23 @@ impl ConfirmDialog {
             button_style: Style::new(),
             selected_button_style: Style::new().underlined(),
             text_style: Style::new().white(),
+            padding: Padding::uniform(2),
+            text_alignment: Alignment::Left,
+            title_alignment: Alignment::Center,
+            severities: Default::default(),
+            mnemonic_display: Default::default(),
+            mnemonic_style: Style::new().underlined(),
+            button_order: Default::default(),
+            destructive_button_style: Style::new().red(),
+            disabled_button_style: Style::new().dark_gray(),
+            button_alignment: Default::default(),
+            equal_button_width: false,
+            button_gap: DEFAULT_BUTTON_GAP,
+            stacked_buttons: false,
+            key_hints: false,
+            key_hint_style: Style::new().dark_gray(),
+            details_height: DEFAULT_DETAILS_HEIGHT,
+            hyperlinks: hyperlink::supported(),
         }
     }
 
@@ -341,8 +590,132 @@ impl ConfirmDialog {
         self
     }
 
-    fn button_paragraph(button: &'_ ButtonLabel, style: Style) -> Paragraph<'_> {
-        Paragraph::new(button.clone().with_style(Some(style)))
+    /// Set the padding around the dialog text
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Set the dialog text alignment
+    pub fn text_alignment(mut self, alignment: Alignment) -> Self {
+        self.text_alignment = alignment;
+        self
+    }
+
+    /// Set the dialog title alignment
+    pub fn title_alignment(mut self, alignment: Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Set how the button mnemonics are shown
+    pub fn mnemonic_display(mut self, display: MnemonicDisplay) -> Self {
+        self.mnemonic_display = display;
+        self
+    }
+
+    /// Set the style applied to the mnemonics in [`MnemonicDisplay::Highlight`] mode
+    pub fn mnemonic_style(mut self, style: Style) -> Self {
+        self.mnemonic_style = style;
+        self
+    }
+
+    /// The size the dialog needs to show `state` without wrapping, before it is clamped to the
+    /// render area
+    pub fn size(&self, state: &ConfirmDialogState) -> Size {
+        let horizontal_padding = self.padding.left.saturating_add(self.padding.right);
+        let vertical_padding = self.padding.top.saturating_add(self.padding.bottom);
+
+        let buttons = [true, false]
+            .into_iter()
+            .filter_map(|yes| state.shown_button(yes))
+            .collect::<Vec<_>>();
+        let widths = self.button_widths(&buttons.iter().map(|b| &**b).collect::<Vec<_>>());
+        let stacked = self.stacked_buttons && buttons.len() > 1;
+        let buttons_width = if stacked {
+            self.widest_button_width(&widths)
+        } else {
+            self.buttons_row_width(&widths)
+        };
+        let checkbox_width = state
+            .dont_ask_again
+            .as_ref()
+            .filter(|_| state.has_dont_ask_again())
+            .map(|label| Line::from(label.as_str()).width() as u16 + 4)
+            .unwrap_or(0);
+        let hints_width = if self.key_hints {
+            self.key_hints_line(state).width() as u16
+        } else {
+            0
+        };
+        let toggle_width = if state.details.is_some() {
+            state.details_toggle_line().width() as u16
+        } else {
+            0
+        };
+        let min_width = buttons_width
+            .max(checkbox_width)
+            .max(hints_width)
+            .max(toggle_width)
+            .saturating_add(horizontal_padding);
+
+        let details = state
+            .details
+            .iter()
+            .filter(|_| state.details_expanded)
+            .flat_map(|details| details.lines.iter());
+        let width = state
+            .text
+            .lines
+            .iter()
+            .chain(details)
+            .map(|line| (line.width() as u16).saturating_add(horizontal_padding))
+            .max()
+            .unwrap_or(min_width)
+            .max(min_width)
+            .max(40);
+
+        let checkbox_height = u16::from(state.has_dont_ask_again());
+        let reason_height = u16::from(state.has_disabled_reason());
+        let buttons_height = if stacked { widths.len() as u16 } else { 1 };
+        let height = (state.text.lines.len() as u16
+            + 1
+            + buttons_height
+            + u16::from(self.key_hints)
+            + u16::from(state.details.is_some())
+            + state.details_height(self.details_height)
+            + checkbox_height
+            + reason_height)
+            .saturating_add(vertical_padding);
+
+        Size::new(width, height)
+    }
+
+    /// The display width of `button` once its mnemonic is rendered
+    pub(crate) fn label_width(&self, button: &ButtonLabel) -> u16 {
+        let highlighted = self.mnemonic_display == MnemonicDisplay::Highlight
+            && button.mnemonic_parts().is_some();
+        (button.len() - if highlighted { 4 } else { 2 }) as u16
+    }
+
+    fn button_paragraph<'a>(&self, button: &'a ButtonLabel, style: Style) -> Paragraph<'a> {
+        match button.mnemonic_parts() {
+            Some((before, mnemonic, after))
+                if self.mnemonic_display == MnemonicDisplay::Highlight =>
+            {
+                Paragraph::new(
+                    Line::from(vec![
+                        Span::raw(before),
+                        Span::styled(mnemonic, self.mnemonic_style),
+                        Span::raw(after),
+                    ])
+                    .style(style),
+                )
+            }
+            _ => Paragraph::new(button.clone().with_style(Some(style))),
+        }
+        .style(style)
+        .alignment(Alignment::Center)
     }
 }
 
@@ -355,125 +728,312 @@ impl Default for ConfirmDialogState {
 impl StatefulWidget for ConfirmDialog {
     type State = ConfirmDialogState;
 
-    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let horizontal_padding = 2u16;
-        let vertical_padding = 2u16;
-        let buttons_padding = 2u16;
+    /// Renders the dialog centered in `area`. When `area` is smaller than [`ConfirmDialog::size`]
+    /// the padding shrinks first, then the buttons are stacked and, as a last resort, the dialog
+    /// becomes a one-line prompt such as `Delete all files? [Y/n]`.
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.button_order = self.button_order;
+        if area.is_empty() {
+            return;
+        }
+        self.padding = super::helper::clamp_padding(self.padding, area);
 
-        let block = Block::default()
-            .title(state.title.clone())
-            .title_alignment(Alignment::Center)
-            .borders(self.borders)
-            .border_type(self.border_type)
-            .border_style(self.border_style)
-            .bg(self.bg);
+        let size = self.size(state);
+        let centered_area = super::helper::centered_rect_with_size(size.width, size.height, area);
 
-        let yes_selected = state.yes_selected || state.no_button.is_none();
+        let border = |side: Borders| u16::from(self.borders.contains(side));
+        let (border_left, border_right) = (border(Borders::LEFT), border(Borders::RIGHT));
+        let (border_top, border_bottom) = (border(Borders::TOP), border(Borders::BOTTOM));
 
-        let yes_button = Self::button_paragraph(
-            &state.yes_button,
-            if yes_selected {
-                self.selected_button_style.clone()
-            } else {
-                self.button_style.clone()
-            },
-        );
-        let yes_button_size = (state.yes_button.len() + buttons_padding as usize) as u16;
-        let mut no_button_size = 0u16;
-
-        let no_button = if let Some(no_button) = &state.no_button {
-            no_button_size = (no_button.len() + buttons_padding as usize) as u16;
-            Some(Self::button_paragraph(
-                no_button,
-                if !yes_selected {
-                    self.selected_button_style.clone()
-                } else {
-                    self.button_style.clone()
-                },
-            ))
-        } else {
-            None
+        let yes_selected = state.yes_selected || state.no_button.is_none();
+        let mut buttons = vec![(
+            state
+                .shown_button(true)
+                .unwrap_or(Cow::Borrowed(&state.yes_button)),
+            yes_selected,
+        )];
+        if let Some(no_button) = state.shown_button(false) {
+            buttons.push((no_button, !yes_selected));
+        }
+        if !state.yes_first() {
+            buttons.reverse();
+        }
+        let widths = self.button_widths(&buttons.iter().map(|(b, _)| &**b).collect::<Vec<_>>());
+        let row_width = self.buttons_row_width(&widths);
+        let widest_width = self.widest_button_width(&widths);
+        let checkbox_height = u16::from(state.has_dont_ask_again());
+        let reason_height = u16::from(state.has_disabled_reason());
+
+        let inner_width = centered_area
+            .width
+            .saturating_sub(border_left + border_right);
+        let stacked = buttons.len() > 1 && (self.stacked_buttons || inner_width < row_width);
+        let buttons_height = if stacked { buttons.len() as u16 + 1 } else { 2 };
+
+        let min_height =
+            border_top + 1 + checkbox_height + reason_height + buttons_height - 1 + border_bottom;
+        if centered_area.height < min_height || inner_width < widest_width {
+            self.render_compact(area, buf, state);
+            return;
+        }
+        // the optional rows share what is left once the text has a line: the details shrink
+        // first, then the footer goes and finally the details toggle
+        let mut spare = centered_area.height - min_height;
+        let mut take = |rows: u16| {
+            let rows = rows.min(spare);
+            spare -= rows;
+            rows
         };
+        let toggle_height = take(u16::from(state.details.is_some()));
+        let hints_height = take(u16::from(self.key_hints));
+        let details_height = take(state.details_height(self.details_height));
+        let details_scroll = state.details_scroll.min(
+            state
+                .details
+                .as_ref()
+                .map_or(0, |details| details.lines.len() as u16)
+                .saturating_sub(details_height),
+        );
 
-        let min_width: u16 = (yes_button_size + no_button_size) + horizontal_padding * 2;
-        let text = state.text.clone();
-
-        let mut width = text
-            .lines
-            .iter()
-            .max_by(|a, b| a.width().cmp(&b.width()))
-            .map(|line| line.width() + horizontal_padding as usize * 2)
-            .unwrap_or(min_width as usize) as u16;
-
-        width = width.max(min_width).max(40);
+        // the padding includes the borders, shrink it down to them when the area is too small.
+        // The text wraps, so only the buttons and the checkbox need the horizontal space
+        let checkbox_width = state
+            .dont_ask_again
+            .as_ref()
+            .filter(|_| checkbox_height > 0)
+            .map(|label| Line::from(label.as_str()).width() as u16 + 4)
+            .unwrap_or(0);
+        let content_width = if stacked { widest_width } else { row_width };
+        let (left, right) = super::helper::shrink_padding(
+            (self.padding.left, self.padding.right),
+            (border_left, border_right),
+            content_width
+                .max(checkbox_width)
+                .saturating_add(self.padding.left)
+                .saturating_add(self.padding.right)
+                .saturating_sub(centered_area.width),
+        );
+        let (top, bottom) = super::helper::shrink_padding(
+            (self.padding.top, self.padding.bottom),
+            (border_top, 0),
+            size.height.saturating_sub(centered_area.height),
+        );
+        let padding = Padding::new(left, right, top, bottom);
+
+        let mut title = state.title.clone();
+        let mut border_style = self.border_style;
+        if let Some(severity) = state.severity {
+            let severity_style = self.severities.get(severity);
+            title = severity_style.decorate_title(title);
+            border_style = border_style.fg(severity_style.color);
+        }
 
-        let height = (text.lines.len() + 1 + (vertical_padding as usize * 2 + 1)) as u16;
+        let mut block = Block::default()
+            .title(super::helper::truncate_line(title, inner_width))
+            .title_alignment(self.title_alignment)
+            .borders(self.borders)
+            .border_type(self.border_type)
+            .border_style(border_style)
+            .bg(self.bg);
+        if state.copied {
+            block = block.title_bottom(
+                super::helper::truncate_line(Line::from(COPIED), inner_width)
+                    .style(self.text_style)
+                    .right_aligned(),
+            );
+        }
 
-        let lines = Text::from(text);
+        let button_style = |button: &ButtonLabel, selected: bool| {
+            let style = if selected {
+                self.selected_button_style
+            } else {
+                self.button_style
+            };
+            let style = if button.role == Some(ButtonRole::Destructive) {
+                style.patch(self.destructive_button_style)
+            } else {
+                style
+            };
+            if button.disabled {
+                style.patch(self.disabled_button_style)
+            } else {
+                style
+            }
+        };
 
-        let text_widget = Paragraph::new(lines)
-            .block(Block::new().padding(Padding::new(
-                horizontal_padding,
-                horizontal_padding,
-                vertical_padding,
-                vertical_padding,
-            )))
+        let text_widget = Paragraph::new(state.text.clone())
+            .block(Block::new().padding(padding))
             .style(self.text_style)
+            .alignment(self.text_alignment)
             .wrap(Wrap { trim: true });
 
-        let centered_area = super::helper::centered_rect_with_size(width, height, area);
-
         let main_layout = Layout::default()
             .direction(Direction::Vertical)
-            .constraints(&[Constraint::Min(1), Constraint::Max(2)])
+            .constraints([
+                Constraint::Min(1),
+                Constraint::Length(toggle_height),
+                Constraint::Length(details_height),
+                Constraint::Length(checkbox_height),
+                Constraint::Length(reason_height),
+                Constraint::Max(buttons_height + hints_height),
+            ])
             .split(centered_area);
 
-        Clear::default().render(centered_area, buf);
+        Clear.render(centered_area, buf);
         text_widget.render(main_layout[0], buf);
         block.render(centered_area, buf);
 
-        let buttons_layout = Layout::default().direction(Direction::Horizontal);
+        if toggle_height > 0 {
+            Paragraph::new(state.details_toggle_line())
+                .block(Block::new().padding(Padding::horizontal(padding.left)))
+                .style(self.text_style)
+                .render(main_layout[1], buf);
+        }
+
+        if let Some(details) = state.details.as_ref().filter(|_| details_height > 0) {
+            Paragraph::new(details.clone())
+                .block(Block::new().padding(Padding::horizontal(padding.left)))
+                .style(self.text_style)
+                .scroll((details_scroll, 0))
+                .render(main_layout[2], buf);
+        }
+
+        if let Some(label) = state
+            .dont_ask_again
+            .as_ref()
+            .filter(|_| checkbox_height > 0)
+        {
+            let mark = if state.dont_ask_again_checked {
+                'x'
+            } else {
+                ' '
+            };
+            Paragraph::new(format!("[{mark}] {label}"))
+                .block(Block::new().padding(Padding::horizontal(padding.left)))
+                .style(self.text_style)
+                .render(main_layout[3], buf);
+        }
 
-        if let Some(no_button) = no_button {
-            let c = (main_layout[1].width - (yes_button_size + no_button_size)) / 2; // 19
+        if let Some(reason) = state.shown_disabled_reason() {
+            Paragraph::new(super::helper::truncate_line(
+                Line::from(reason),
+                inner_width.saturating_sub(
+                    (padding.left + padding.right).saturating_sub(border_left + border_right),
+                ),
+            ))
+            .block(Block::new().padding(Padding::horizontal(padding.left)))
+            .style(self.text_style.patch(self.disabled_button_style))
+            .render(main_layout[4], buf);
+        }
 
-            let buttons_layout = buttons_layout
-                .constraints(&[
-                    Constraint::Length(c),
-                    Constraint::Max(yes_button_size),
-                    Constraint::Max(no_button_size),
-                    Constraint::Length(c),
-                ])
-                .split(main_layout[1]);
+        let buttons_area = Rect {
+            x: centered_area.x + padding.left,
+            width: centered_area
+                .width
+                .saturating_sub(padding.left + padding.right),
+            ..main_layout[5]
+        };
+        let inner_area = Rect {
+            x: centered_area.x + border_left,
+            width: inner_width,
+            ..main_layout[5]
+        };
 
-            yes_button.render(buttons_layout[1], buf);
-            no_button.render(buttons_layout[2], buf);
-        } else {
-            let c = (main_layout[1].width - yes_button_size) / 2;
-            let buttons_layout = buttons_layout
-                .constraints(&[
-                    Constraint::Length(c),
-                    Constraint::Max(yes_button_size),
-                    Constraint::Length(c),
-                ])
-                .split(main_layout[1]);
-
-            yes_button.render(buttons_layout[1], buf);
+        let rects = self.button_rects(buttons_area, &widths, stacked);
+        for ((button, selected), rect) in buttons.iter().zip(rects) {
+            self.button_paragraph(button, button_style(button, *selected))
+                .render(rect.intersection(inner_area), buf);
+        }
+
+        if hints_height > 0 {
+            let hints_area = Rect {
+                y: buttons_area.y + buttons_height - 1,
+                height: 1,
+                ..buttons_area
+            };
+            Paragraph::new(super::helper::truncate_line(
+                self.key_hints_line(state),
+                hints_area.width,
+            ))
+            .alignment(Alignment::Center)
+            .render(hints_area.intersection(inner_area), buf);
         }
+
+        if self.hyperlinks {
+            hyperlink::render_hyperlinks(main_layout[0], buf, &state.hyperlinks);
+            hyperlink::render_hyperlinks(main_layout[2], buf, &state.hyperlinks);
+        }
+
+        state.details_viewport = details_height;
+        state.details_scroll = details_scroll;
     }
 }
 
-impl Display for TryFromSliceError {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        #[allow(deprecated)]
-        self.description().fmt(f)
+impl ConfirmDialog {
+    /// One-line fallback for areas too small for the dialog: the text followed by the button
+    /// mnemonics, the selected one uppercased
+    fn render_compact(&self, area: Rect, buf: &mut Buffer, state: &ConfirmDialogState) {
+        let yes_selected = state.yes_selected || state.no_button.is_none();
+        let mnemonic = |button: &ButtonLabel, selected: bool| {
+            if selected {
+                button.control.to_uppercase().to_string()
+            } else {
+                button.control.to_string()
+            }
+        };
+
+        let mut mnemonics = vec![mnemonic(&state.yes_button, yes_selected)];
+        if let Some(no_button) = &state.no_button {
+            mnemonics.push(mnemonic(no_button, !yes_selected));
+        }
+        if !state.yes_first() {
+            mnemonics.reverse();
+        }
+        let hint = format!("[{}]", mnemonics.join("/"));
+
+        let prompt = state
+            .text
+            .lines
+            .iter()
+            .flat_map(|line| line.spans.iter().map(|span| span.content.as_ref()))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let hint_width = hint.width() as u16;
+        let prompt = super::helper::truncate_line(
+            Line::styled(prompt, self.text_style),
+            area.width.saturating_sub(hint_width + 1),
+        );
+
+        let mut spans = prompt.spans;
+        spans.push(" ".into());
+        spans.push(Span::styled(hint, self.selected_button_style));
+        let line = super::helper::truncate_line(Line::from(spans), area.width);
+
+        let line_area = super::helper::centered_rect_with_size(line.width() as u16, 1, area);
+        Clear.render(line_area, buf);
+        Paragraph::new(line)
+            .style(Style::new().bg(self.bg))
+            .render(line_area, buf);
     }
 }
 
-impl Error for TryFromSliceError {
-    #[allow(deprecated)]
-    fn description(&self) -> &str {
-        "could not convert slice to array"
+impl Display for ButtonLabelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ButtonLabelError::Empty => write!(f, "button label must not be empty"),
+            ButtonLabelError::WhitespaceOnly => {
+                write!(f, "button label must not be empty or only whitespace")
+            }
+            ButtonLabelError::MultipleMnemonics { label } => write!(
+                f,
+                "button label `{label}` has more than one mnemonic marker, keep a single `(x)` or `&x`"
+            ),
+            ButtonLabelError::InvalidMnemonic { label, mnemonic } => write!(
+                f,
+                "button label `{label}` has an invalid mnemonic `{mnemonic}`, use a letter or a digit"
+            ),
+        }
     }
 }
+
+impl Error for ButtonLabelError {}
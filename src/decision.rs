@@ -0,0 +1,153 @@
+use std::io;
+use std::sync::{Arc, Mutex};
+
+use crate::{ConfirmDialogState, DecisionStore, MemoryDecisionStore, SharedDecisionStore};
+
+impl MemoryDecisionStore {
+    pub fn new() -> Self {
+        MemoryDecisionStore::default()
+    }
+
+    /// Wrap the store so it can be shared between dialogs
+    pub fn shared(self) -> SharedDecisionStore {
+        Arc::new(Mutex::new(self))
+    }
+}
+
+impl DecisionStore for MemoryDecisionStore {
+    fn get(&self, id: u16) -> Option<bool> {
+        self.decisions.get(&id).copied()
+    }
+
+    fn set(&mut self, id: u16, answer: bool) -> io::Result<()> {
+        self.decisions.insert(id, answer);
+        Ok(())
+    }
+
+    fn remove(&mut self, id: u16) -> io::Result<()> {
+        self.decisions.remove(&id);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl crate::JsonFileDecisionStore {
+    /// Open the store at `path`. A missing file is treated as an empty store and will be
+    /// created on the first decision.
+    pub fn open<P>(path: P) -> io::Result<Self>
+    where
+        P: Into<std::path::PathBuf>,
+    {
+        let path = path.into();
+        let decisions = match std::fs::read_to_string(&path) {
+            Ok(content) if content.trim().is_empty() => Default::default(),
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Default::default(),
+            Err(err) => return Err(err),
+        };
+        Ok(crate::JsonFileDecisionStore { path, decisions })
+    }
+
+    /// Wrap the store so it can be shared between dialogs
+    pub fn shared(self) -> SharedDecisionStore {
+        Arc::new(Mutex::new(self))
+    }
+
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+
+    fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(&self.decisions)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        std::fs::write(&self.path, content)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl DecisionStore for crate::JsonFileDecisionStore {
+    fn get(&self, id: u16) -> Option<bool> {
+        self.decisions.get(&id).copied()
+    }
+
+    fn set(&mut self, id: u16, answer: bool) -> io::Result<()> {
+        self.decisions.insert(id, answer);
+        self.save()
+    }
+
+    fn remove(&mut self, id: u16) -> io::Result<()> {
+        if self.decisions.remove(&id).is_some() {
+            self.save()
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl ConfirmDialogState {
+    /// Show a "Don't ask again" checkbox, toggled with `Space`. When it is ticked the answer is
+    /// saved in `store`, and opening a dialog with the same id again resolves immediately with
+    /// the remembered answer.
+    pub fn with_dont_ask_again(&mut self, store: SharedDecisionStore) -> &mut Self {
+        if self.dont_ask_again.is_none() {
+            self.dont_ask_again = Some(crate::Translations::english().dont_ask_again);
+        }
+        self.decision_store = Some(store);
+        self
+    }
+
+    /// Set the label of the "Don't ask again" checkbox
+    pub fn with_dont_ask_again_label<S>(&mut self, label: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.dont_ask_again = Some(label.into());
+        self
+    }
+
+    /// Remove the "Don't ask again" checkbox
+    pub fn without_dont_ask_again(&mut self) -> &mut Self {
+        self.dont_ask_again = None;
+        self.decision_store = None;
+        self
+    }
+
+    /// Check if the "Don't ask again" checkbox is ticked
+    pub fn is_dont_ask_again_checked(&self) -> bool {
+        self.dont_ask_again_checked
+    }
+
+    /// Why the answer of the ticked "Don't ask again" checkbox could not be saved, until the
+    /// dialog is opened again
+    pub fn last_store_error(&self) -> Option<&io::Error> {
+        self.store_error.as_deref()
+    }
+
+    pub(crate) fn has_dont_ask_again(&self) -> bool {
+        self.dont_ask_again.is_some() && self.decision_store.is_some()
+    }
+
+    pub(crate) fn remembered_decision(&self) -> Option<bool> {
+        self.decision_store
+            .as_ref()
+            .and_then(|store| store.lock().ok()?.get(self.id))
+    }
+
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn remember_decision(&mut self, answer: bool) {
+        if !self.dont_ask_again_checked {
+            return;
+        }
+        if let Some(store) = self.decision_store.as_ref() {
+            let result = match store.lock() {
+                Ok(mut store) => store.set(self.id, answer),
+                Err(_) => Err(io::Error::other("the decision store is poisoned")),
+            };
+            self.store_error = result.err().map(Arc::new);
+        }
+    }
+}
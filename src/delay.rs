@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+use std::time::Duration;
+
+use crate::{ButtonLabel, ConfirmDialogState};
+
+impl ConfirmDialogState {
+    /// Keep the affirmative button disabled for `delay` after [`ConfirmDialogState::open`],
+    /// counting down the seconds left in its label
+    pub fn with_enable_delay(&mut self, delay: Duration) -> &mut Self {
+        self.enable_delay = delay;
+        self
+    }
+
+    /// Ignore `Enter` and the mnemonics for `guard` after [`ConfirmDialogState::open`], so a key
+    /// pressed right before the dialog showed up does not answer it
+    pub fn with_input_guard(&mut self, guard: Duration) -> &mut Self {
+        self.input_guard = guard;
+        self
+    }
+
+    /// The time left before the affirmative button is enabled, `None` once it is
+    pub fn remaining_delay(&self) -> Option<Duration> {
+        self.remaining(self.enable_delay)
+    }
+
+    /// How long an event loop can wait for a key before the countdown label changes,
+    /// `None` when there is no countdown to redraw
+    pub fn next_tick(&self) -> Option<Duration> {
+        self.remaining_delay().map(|remaining| {
+            let tick = Duration::from_nanos(remaining.subsec_nanos().into());
+            if tick.is_zero() {
+                Duration::from_secs(1)
+            } else {
+                tick
+            }
+        })
+    }
+
+    /// The time left out of `duration` since the dialog was opened
+    fn remaining(&self, duration: Duration) -> Option<Duration> {
+        let opened_at = self.opened_at.filter(|_| self.opened)?;
+        Some(duration.saturating_sub(opened_at.elapsed())).filter(|left| !left.is_zero())
+    }
+
+    /// Whether the button answering `yes` is waiting for the enable delay
+    pub(crate) fn is_delayed(&self, yes: bool) -> bool {
+        yes == self.affirmative_answer() && self.remaining_delay().is_some()
+    }
+
+    /// Whether `Enter` and the mnemonics are still ignored
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn is_guarded(&self) -> bool {
+        self.remaining(self.input_guard).is_some()
+    }
+
+    /// The answer of the affirmative button, the `yes` one unless only the `no` button is
+    /// affirmative
+    fn affirmative_answer(&self) -> bool {
+        self.yes_role().is_affirmative()
+            || !self.no_role().is_some_and(|role| role.is_affirmative())
+    }
+
+    /// The button answering `yes` as it is rendered, with the countdown while it is delayed
+    pub(crate) fn shown_button(&self, yes: bool) -> Option<Cow<'_, ButtonLabel>> {
+        let button = self.button(yes)?;
+        if !self.is_delayed(yes) {
+            return Some(Cow::Borrowed(button));
+        }
+
+        let seconds = self
+            .remaining_delay()
+            .unwrap_or_default()
+            .as_secs_f32()
+            .ceil();
+        let mut button = button.clone();
+        button.label = format!("{} ({seconds})", button.label);
+        button.disabled = true;
+        Some(Cow::Owned(button))
+    }
+}
@@ -0,0 +1,87 @@
+use ratatui_core::text::{Line, Text};
+
+use crate::{ConfirmDialog, ConfirmDialogState};
+
+/// The rows shown by expanded details before they scroll
+pub(crate) const DEFAULT_DETAILS_HEIGHT: u16 = 8;
+
+impl ConfirmDialogState {
+    /// Set details hidden below the text until they are expanded with `Tab`
+    pub fn with_details<T>(&mut self, details: T) -> &mut Self
+    where
+        T: Into<Text<'static>>,
+    {
+        self.details = Some(details.into());
+        self.details_scroll = 0;
+        self
+    }
+
+    /// Remove the details
+    pub fn without_details(&mut self) -> &mut Self {
+        self.details = None;
+        self.details_expanded = false;
+        self.details_scroll = 0;
+        self
+    }
+
+    /// Expand or collapse the details
+    pub fn with_details_expanded(&mut self, expanded: bool) -> &mut Self {
+        self.details_expanded = expanded && self.details.is_some();
+        self.details_scroll = 0;
+        self
+    }
+
+    /// Check if the details are shown
+    pub fn is_details_expanded(&self) -> bool {
+        self.details_expanded
+    }
+
+    /// The first details line shown
+    pub fn details_scroll(&self) -> u16 {
+        self.details_scroll
+    }
+
+    /// Expand the details if they are collapsed, collapse them otherwise
+    pub fn toggle_details(&mut self) -> &mut Self {
+        self.with_details_expanded(!self.details_expanded)
+    }
+
+    /// Scroll the expanded details by `lines`, up when negative, without going past their end
+    /// as last rendered
+    pub fn scroll_details(&mut self, lines: i32) -> &mut Self {
+        let count = self
+            .details
+            .as_ref()
+            .map_or(0, |details| details.lines.len());
+        let max = u16::try_from(count)
+            .unwrap_or(u16::MAX)
+            .saturating_sub(self.details_viewport);
+        let scroll = i32::from(self.details_scroll).saturating_add(lines);
+        self.details_scroll = scroll.clamp(0, i32::from(max)) as u16;
+        self
+    }
+
+    /// The rows the details take once expanded, at most `max_height`
+    pub(crate) fn details_height(&self, max_height: u16) -> u16 {
+        match &self.details {
+            Some(details) if self.details_expanded => {
+                u16::try_from(details.lines.len()).map_or(max_height, |len| len.min(max_height))
+            }
+            _ => 0,
+        }
+    }
+
+    /// The row toggling the details, e.g. `▸ Details (Tab)`
+    pub(crate) fn details_toggle_line(&self) -> Line<'static> {
+        let marker = if self.details_expanded { '▾' } else { '▸' };
+        Line::from(format!("{marker} Details (Tab)"))
+    }
+}
+
+impl ConfirmDialog {
+    /// Set the rows shown by expanded details, longer details scroll with the arrow keys
+    pub fn details_height(mut self, height: u16) -> Self {
+        self.details_height = height;
+        self
+    }
+}
@@ -0,0 +1,91 @@
+use ratatui_core::style::Style;
+
+use crate::{ButtonLabel, ConfirmDialog, ConfirmDialogState};
+
+impl ButtonLabel {
+    /// Disable or enable the button. A disabled button is shown but cannot be pressed.
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Disable the button, showing `reason` when it is focused or pressed
+    pub fn with_disabled_reason<S>(mut self, reason: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.disabled = true;
+        self.disabled_reason = Some(reason.into());
+        self
+    }
+
+    /// Check if the button is disabled
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Why the button is disabled
+    pub fn disabled_reason(&self) -> Option<&str> {
+        self.disabled_reason.as_deref()
+    }
+}
+
+impl ConfirmDialogState {
+    /// Disable or enable the `yes` button, keeping its reason
+    pub fn with_yes_disabled(&mut self, disabled: bool) -> &mut Self {
+        self.yes_button.disabled = disabled;
+        self.triggered_disabled = None;
+        self
+    }
+
+    /// Disable or enable the `no` button, keeping its reason
+    pub fn with_no_disabled(&mut self, disabled: bool) -> &mut Self {
+        if let Some(button) = self.no_button.as_mut() {
+            button.disabled = disabled;
+        }
+        self.triggered_disabled = None;
+        self
+    }
+
+    /// The `yes` button for `true`, the `no` button for `false`
+    pub(crate) fn button(&self, yes: bool) -> Option<&ButtonLabel> {
+        if yes {
+            Some(&self.yes_button)
+        } else {
+            self.no_button.as_ref()
+        }
+    }
+
+    /// Check if the button answering `yes` exists and can be pressed right now
+    pub(crate) fn is_enabled(&self, yes: bool) -> bool {
+        self.button(yes).is_some_and(|button| !button.disabled) && !self.is_delayed(yes)
+    }
+
+    /// The reason to show: the one of the disabled button that was last pressed, or else of
+    /// the focused one
+    pub(crate) fn shown_disabled_reason(&self) -> Option<&str> {
+        let yes_selected = self.yes_selected || self.no_button.is_none();
+        self.triggered_disabled
+            .into_iter()
+            .chain([yes_selected])
+            .filter_map(|yes| self.button(yes))
+            .find(|button| button.disabled)
+            .and_then(ButtonLabel::disabled_reason)
+    }
+
+    /// Whether a line is reserved for the disabled reasons, so the dialog does not resize
+    pub(crate) fn has_disabled_reason(&self) -> bool {
+        [true, false]
+            .into_iter()
+            .filter_map(|yes| self.button(yes))
+            .any(|button| button.disabled && button.disabled_reason.is_some())
+    }
+}
+
+impl ConfirmDialog {
+    /// Set the style patched over the style of disabled buttons and used for their reason
+    pub fn disabled_button_style(mut self, style: Style) -> Self {
+        self.disabled_button_style = style;
+        self
+    }
+}
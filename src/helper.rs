@@ -1,4 +1,9 @@
 use ratatui_core::layout::{Constraint, Direction, Layout, Rect};
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Span};
+use ratatui_widgets::block::Padding;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// helper function to create a centered rect using up certain percentage of the available rect `r`
 #[allow(dead_code)]
@@ -22,8 +27,8 @@ pub(crate) fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
-#[allow(dead_code)]
-pub(crate) fn centered_rect_with_size(width: u16, height: u16, r: Rect) -> Rect {
+/// helper function to create a centered rect of the given size, clamped to the available rect `r`
+pub fn centered_rect_with_size(width: u16, height: u16, r: Rect) -> Rect {
     let width = width.min(r.width);
     let height = height.min(r.height);
     let remaining_width = r.width.saturating_sub(width);
@@ -38,14 +43,75 @@ pub(crate) fn centered_rect_with_size(width: u16, height: u16, r: Rect) -> Rect
         ])
         .split(r);
 
-    let final_area = Layout::default()
+    Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
             Constraint::Max(remaining_width / 2),
             Constraint::Length(width),
             Constraint::Max(remaining_width / 2),
         ])
-        .split(popup_layout[1])[1];
+        .split(popup_layout[1])[1]
+}
+
+/// Cut `line` to `width` columns, ending it with an ellipsis when something was cut
+pub(crate) fn truncate_line(line: Line<'_>, width: u16) -> Line<'_> {
+    let width = width as usize;
+    if line.width() <= width {
+        return line;
+    }
+
+    let mut spans = Vec::new();
+    let mut used = 0;
+    let mut style = Style::default();
+    'spans: for span in &line.spans {
+        style = span.style;
+        let mut content = String::new();
+        for grapheme in span.content.graphemes(true) {
+            let grapheme_width = grapheme.width();
+            if used + grapheme_width >= width {
+                spans.push(Span::styled(content, span.style));
+                break 'spans;
+            }
+            used += grapheme_width;
+            content.push_str(grapheme);
+        }
+        spans.push(Span::styled(content, span.style));
+    }
+    if width > 0 {
+        spans.push(Span::styled("…", style));
+    }
+
+    Line {
+        spans,
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
+
+/// Limit every side of `padding` to half of `area`, which is all it could ever take
+pub(crate) fn clamp_padding(padding: Padding, area: Rect) -> Padding {
+    Padding::new(
+        padding.left.min(area.width / 2),
+        padding.right.min(area.width / 2),
+        padding.top.min(area.height / 2),
+        padding.bottom.min(area.height / 2),
+    )
+}
 
-    final_area
+/// Reduce the paddings `(first, second)` towards `min`, one cell at a time starting from the
+/// larger one, until `overflow` cells are freed or both reached their minimum
+pub(crate) fn shrink_padding(
+    (mut first, mut second): (u16, u16),
+    min: (u16, u16),
+    mut overflow: u16,
+) -> (u16, u16) {
+    while overflow > 0 && (first > min.0 || second > min.1) {
+        if second > min.1 && (second >= first || first <= min.0) {
+            second -= 1;
+        } else {
+            first -= 1;
+        }
+        overflow -= 1;
+    }
+    (first, second)
 }
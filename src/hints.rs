@@ -0,0 +1,53 @@
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Span};
+
+use crate::{ConfirmDialog, ConfirmDialogState, KeyHint};
+
+const SEPARATOR: &str = " · ";
+
+impl ConfirmDialogState {
+    /// The keys `ConfirmDialogState::handle` currently reacts to, besides the mnemonics
+    /// shown in the button labels
+    pub fn key_hints(&self) -> Vec<KeyHint> {
+        self.bindings()
+            .iter()
+            .map(|binding| binding.hint())
+            .collect()
+    }
+}
+
+impl KeyHint {
+    /// Create a hint for `key`
+    pub fn new(key: impl Into<String>, action: &'static str) -> Self {
+        KeyHint {
+            key: key.into(),
+            action,
+        }
+    }
+}
+
+impl ConfirmDialog {
+    /// Show the active key bindings on a footer line inside the border
+    pub fn key_hints(mut self, show: bool) -> Self {
+        self.key_hints = show;
+        self
+    }
+
+    /// Set the style of the key hints footer
+    pub fn key_hint_style(mut self, style: Style) -> Self {
+        self.key_hint_style = style;
+        self
+    }
+
+    /// The footer listing the key hints of `state`, e.g. `←/→ select · Enter confirm`
+    pub(crate) fn key_hints_line(&self, state: &ConfirmDialogState) -> Line<'static> {
+        let mut spans = Vec::new();
+        for hint in state.key_hints() {
+            if !spans.is_empty() {
+                spans.push(Span::raw(SEPARATOR));
+            }
+            spans.push(Span::raw(format!("{} {}", hint.key, hint.action)));
+        }
+        Line::from(spans).style(self.key_hint_style)
+    }
+}
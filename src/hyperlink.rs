@@ -0,0 +1,157 @@
+use std::borrow::Cow;
+use std::num::NonZeroU16;
+use std::sync::LazyLock;
+
+use ratatui_core::buffer::{Buffer, CellDiffOption};
+use ratatui_core::layout::Rect;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{ConfirmDialog, ConfirmDialogState, PopupMessage};
+
+/// Whether the terminal is known to support OSC 8 hyperlinks, from the environment
+static SUPPORTED: LazyLock<bool> = LazyLock::new(|| {
+    let var = |name: &str| std::env::var(name).ok();
+
+    if let Some(value) = var("FORCE_HYPERLINK") {
+        return value != "0";
+    }
+    if var("WT_SESSION").is_some() || var("KONSOLE_VERSION").is_some() {
+        return true;
+    }
+    if var("VTE_VERSION")
+        .and_then(|version| version.parse::<u32>().ok())
+        .is_some_and(|version| version >= 5000)
+    {
+        return true;
+    }
+    if var("TERM_PROGRAM").is_some_and(|program| {
+        matches!(
+            program.as_str(),
+            "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper"
+        )
+    }) {
+        return true;
+    }
+    var("TERM").is_some_and(|term| {
+        ["kitty", "alacritty", "foot", "ghostty", "wezterm"]
+            .iter()
+            .any(|name| term.contains(name))
+    })
+});
+
+/// Whether hyperlinks are emitted by default: `$FORCE_HYPERLINK` when set, otherwise whether
+/// the terminal is known to support them
+pub(crate) fn supported() -> bool {
+    *SUPPORTED
+}
+
+impl ConfirmDialogState {
+    /// Make every occurrence of `text` in the dialog text and details a link to `url`.
+    /// The text must not be wrapped over two lines.
+    pub fn with_hyperlink<T, U>(&mut self, text: T, url: U) -> &mut Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        self.hyperlinks
+            .push((text.into(), sanitize_url(url.into())));
+        self
+    }
+}
+
+impl ConfirmDialog {
+    /// Emit the hyperlinks of the state as OSC 8 escape sequences, on by default when the
+    /// terminal is known to support them. Without them the links are plain text.
+    pub fn hyperlinks(mut self, enabled: bool) -> Self {
+        self.hyperlinks = enabled;
+        self
+    }
+}
+
+impl PopupMessage<'_, '_> {
+    /// Make every occurrence of `text` in the message a link to `url`, see
+    /// [`ConfirmDialogState::with_hyperlink`]
+    pub fn hyperlink<T, U>(mut self, text: T, url: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        self.links.push((text.into(), sanitize_url(url.into())));
+        self
+    }
+
+    /// Emit the hyperlinks as OSC 8 escape sequences, see [`ConfirmDialog::hyperlinks`]
+    pub fn hyperlinks(mut self, enabled: bool) -> Self {
+        self.hyperlinks = enabled;
+        self
+    }
+}
+
+/// Drop the control characters of `url`, which could end the OSC 8 sequence early and inject
+/// other escape sequences
+fn sanitize_url(url: String) -> String {
+    if url.chars().any(char::is_control) {
+        url.chars().filter(|chr| !chr.is_control()).collect()
+    } else {
+        url
+    }
+}
+
+/// Wrap the cells of `area` showing the text of `links` in OSC 8 escape sequences. The cells
+/// keep the width of their symbol so the rest of the line does not move.
+pub(crate) fn render_hyperlinks(area: Rect, buf: &mut Buffer, links: &[(String, String)]) {
+    let area = area.intersection(buf.area);
+    if links.is_empty() || area.is_empty() {
+        return;
+    }
+
+    for y in area.top()..area.bottom() {
+        // the symbols of the row, with the byte offset where each cell starts
+        let mut row = String::new();
+        let mut cells = Vec::new();
+        let mut x = area.left();
+        while x < area.right() {
+            let symbol = strip_hyperlink(buf[(x, y)].symbol());
+            let width = symbol.width().max(1) as u16;
+            cells.push((row.len(), x, width));
+            row.push_str(&symbol);
+            x += width;
+        }
+
+        let mut wrapped = Vec::new();
+        for (text, url) in links.iter().filter(|(text, _)| !text.is_empty()) {
+            for (start, matched) in row.match_indices(text.as_str()) {
+                let end = start + matched.len();
+                wrapped.extend(
+                    cells
+                        .iter()
+                        .filter(|(offset, _, _)| (start..end).contains(offset))
+                        .map(|&(_, x, width)| (x, width, url)),
+                );
+            }
+        }
+
+        // the first link wins where they overlap
+        wrapped.sort_by_key(|&(x, _, _)| x);
+        wrapped.dedup_by_key(|&mut (x, _, _)| x);
+        for (x, width, url) in wrapped {
+            let cell = &mut buf[(x, y)];
+            let symbol = strip_hyperlink(cell.symbol()).into_owned();
+            let symbol = format!("\x1b]8;;{url}\x1b\\{symbol}\x1b]8;;\x1b\\");
+            cell.set_symbol(&symbol);
+            cell.set_diff_option(CellDiffOption::ForcedWidth(
+                NonZeroU16::new(width).unwrap_or(NonZeroU16::MIN),
+            ));
+        }
+    }
+}
+
+/// `symbol` without the OSC 8 escape sequences wrapped around it
+pub(crate) fn strip_hyperlink(symbol: &str) -> Cow<'_, str> {
+    let Some(rest) = symbol.strip_prefix("\x1b]8;") else {
+        return Cow::Borrowed(symbol);
+    };
+    let text = rest.split_once("\x1b\\").map_or(rest, |(_, text)| text);
+    let text = text.split_once("\x1b]8;").map_or(text, |(text, _)| text);
+    Cow::Owned(text.to_string())
+}
@@ -0,0 +1,81 @@
+use std::io::{self, Stdout};
+
+use ratatui::backend::CrosstermBackend;
+use ratatui::crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use ratatui::layout::{Position, Rect};
+use ratatui::{Terminal, TerminalOptions, Viewport};
+
+use crate::run::event_loop;
+use crate::{ConfirmDialog, ConfirmDialogState, DialogOutcome};
+
+/// Restores the cooked mode when dropped, even if drawing fails
+struct RawModeGuard;
+
+impl RawModeGuard {
+    fn new() -> io::Result<Self> {
+        enable_raw_mode()?;
+        Ok(RawModeGuard)
+    }
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+    }
+}
+
+/// Ask the question of `state` in an inline viewport below the cursor, without switching to the
+/// alternate screen. The viewport is as tall as the dialog and the dialog is left in the
+/// scrollback once answered.
+///
+/// The listener of `state`, if any, is notified as usual. `Ctrl+C` cancels the dialog as in
+/// [`run_confirm`](crate::run_confirm).
+pub fn confirm_inline(
+    state: &mut ConfirmDialogState,
+    dialog: ConfirmDialog,
+) -> io::Result<DialogOutcome> {
+    run_inline(state, dialog)?;
+    Ok(state.outcome().unwrap_or(DialogOutcome::Cancelled))
+}
+
+fn run_inline(state: &mut ConfirmDialogState, dialog: ConfirmDialog) -> io::Result<()> {
+    state.open();
+    if !state.is_opened() {
+        // resolved with a remembered decision
+        return Ok(());
+    }
+
+    let size = dialog.size(state);
+    let _guard = RawModeGuard::new()?;
+    let mut terminal: Terminal<CrosstermBackend<Stdout>> = Terminal::with_options(
+        CrosstermBackend::new(io::stdout()),
+        TerminalOptions {
+            viewport: Viewport::Inline(size.height),
+        },
+    )?;
+
+    let draw = |terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+                state: &mut ConfirmDialogState| {
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                let area = Rect {
+                    width: size.width.min(area.width),
+                    ..area
+                };
+                f.render_stateful_widget(dialog.clone(), area, state);
+            })
+            .map(|_| ())
+    };
+
+    event_loop(state, |state| draw(&mut terminal, state))?;
+
+    // leave the answered dialog in the scrollback and move below it
+    draw(&mut terminal, state)?;
+    let area = terminal.get_frame().area();
+    terminal.set_cursor_position(Position::new(0, area.bottom().saturating_sub(1)))?;
+    terminal.show_cursor()?;
+    drop(_guard);
+    println!();
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use std::fmt;
+
+#[cfg(feature = "crossterm")]
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::{ConfirmDialogState, Key, KeyHint};
+
+/// What a bound key does in `ConfirmDialogState::handle`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Action {
+    Cancel,
+    ToggleCheckbox,
+    Copy,
+    /// Select the button shown first (`true`) or last (`false`)
+    SelectFirst(bool),
+    Confirm,
+    ToggleDetails,
+    /// Scroll the details by lines
+    Scroll(i32),
+    /// Scroll the details by pages
+    Page(i32),
+}
+
+/// The keys sharing one key hint, e.g. `←/→ select`
+pub(crate) struct Binding {
+    pub(crate) keys: Vec<(Key, Action)>,
+    pub(crate) action: &'static str,
+}
+
+impl Binding {
+    fn new(keys: Vec<(Key, Action)>, action: &'static str) -> Self {
+        Binding { keys, action }
+    }
+
+    /// The hint of the binding, its keys separated with `/`
+    pub(crate) fn hint(&self) -> KeyHint {
+        let keys = self.keys.iter().map(|(key, _)| key.to_string());
+        KeyHint::new(keys.collect::<Vec<_>>().join("/"), self.action)
+    }
+}
+
+impl ConfirmDialogState {
+    /// The bindings that currently do something, in the order of their hints. Mnemonics are
+    /// handled separately since the button labels show them.
+    pub(crate) fn bindings(&self) -> Vec<Binding> {
+        let mut bindings = Vec::new();
+        if self.is_enabled(!self.yes_selected) {
+            bindings.push(Binding::new(
+                vec![
+                    (Key::Left, Action::SelectFirst(true)),
+                    (Key::Right, Action::SelectFirst(false)),
+                ],
+                "select",
+            ));
+        }
+        if self.details.is_some() {
+            bindings.push(Binding::new(
+                vec![(Key::Tab, Action::ToggleDetails)],
+                "details",
+            ));
+        }
+        if self.details_expanded {
+            bindings.push(Binding::new(
+                vec![
+                    (Key::Up, Action::Scroll(-1)),
+                    (Key::Down, Action::Scroll(1)),
+                ],
+                "scroll",
+            ));
+            bindings.push(Binding::new(
+                vec![
+                    (Key::PageUp, Action::Page(-1)),
+                    (Key::PageDown, Action::Page(1)),
+                ],
+                "page",
+            ));
+        }
+        if self.has_dont_ask_again() {
+            bindings.push(Binding::new(
+                vec![(Key::Char(' '), Action::ToggleCheckbox)],
+                "toggle",
+            ));
+        }
+        bindings.push(Binding::new(vec![(Key::Enter, Action::Confirm)], "confirm"));
+        if !self.modal {
+            bindings.push(Binding::new(vec![(Key::Esc, Action::Cancel)], "cancel"));
+        }
+        if let Some(key) = self.copy_key {
+            bindings.push(Binding::new(vec![(key, Action::Copy)], "copy"));
+        }
+        bindings
+    }
+
+    /// The action `event` is bound to right now
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn action(&self, event: &KeyEvent) -> Option<Action> {
+        self.bindings()
+            .into_iter()
+            .flat_map(|binding| binding.keys)
+            .find(|(key, _)| key.matches(event))
+            .map(|(_, action)| action)
+    }
+}
+
+impl Key {
+    /// Check if `event` is this key, regardless of its kind
+    #[cfg(feature = "crossterm")]
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        let chord = event.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT);
+        match (*self, event.code) {
+            (Key::Char(key), KeyCode::Char(chr)) => key == chr && chord.is_empty(),
+            (Key::Ctrl(key), KeyCode::Char(chr)) => {
+                key.eq_ignore_ascii_case(&chr) && chord == KeyModifiers::CONTROL
+            }
+            (Key::Alt(key), KeyCode::Char(chr)) => {
+                key.eq_ignore_ascii_case(&chr) && chord == KeyModifiers::ALT
+            }
+            (Key::F(key), KeyCode::F(number)) => key == number,
+            (Key::Esc, KeyCode::Esc)
+            | (Key::Enter, KeyCode::Enter)
+            | (Key::Tab, KeyCode::Tab)
+            | (Key::Left, KeyCode::Left)
+            | (Key::Right, KeyCode::Right)
+            | (Key::Up, KeyCode::Up)
+            | (Key::Down, KeyCode::Down)
+            | (Key::PageUp, KeyCode::PageUp)
+            | (Key::PageDown, KeyCode::PageDown) => true,
+            _ => false,
+        }
+    }
+}
+
+/// The key as shown in the key hints, e.g. `Ctrl+C`
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Key::Char(' ') => f.write_str("Space"),
+            Key::Char(chr) => write!(f, "{chr}"),
+            Key::Ctrl(chr) => write!(f, "Ctrl+{}", chr.to_uppercase()),
+            Key::Alt(chr) => write!(f, "Alt+{}", chr.to_uppercase()),
+            Key::F(number) => write!(f, "F{number}"),
+            Key::Esc => f.write_str("Esc"),
+            Key::Enter => f.write_str("Enter"),
+            Key::Tab => f.write_str("Tab"),
+            Key::Left => f.write_str("←"),
+            Key::Right => f.write_str("→"),
+            Key::Up => f.write_str("↑"),
+            Key::Down => f.write_str("↓"),
+            Key::PageUp => f.write_str("PgUp"),
+            Key::PageDown => f.write_str("PgDn"),
+        }
+    }
+}
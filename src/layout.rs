@@ -0,0 +1,109 @@
+use ratatui_core::layout::Rect;
+
+use crate::{ButtonAlignment, ButtonLabel, ConfirmDialog};
+
+/// The columns between two buttons
+pub(crate) const DEFAULT_BUTTON_GAP: u16 = 4;
+
+impl ConfirmDialog {
+    /// Set how the buttons are placed on their row
+    pub fn button_alignment(mut self, alignment: ButtonAlignment) -> Self {
+        self.button_alignment = alignment;
+        self
+    }
+
+    /// Give all the buttons the width of the widest label
+    pub fn equal_button_width(mut self, equal: bool) -> Self {
+        self.equal_button_width = equal;
+        self
+    }
+
+    /// Set the number of columns between two buttons
+    pub fn button_gap(mut self, gap: u16) -> Self {
+        self.button_gap = gap;
+        self
+    }
+
+    /// Always show the buttons one per row, they are otherwise only stacked when the dialog
+    /// is too narrow
+    pub fn stacked_buttons(mut self, stacked: bool) -> Self {
+        self.stacked_buttons = stacked;
+        self
+    }
+
+    /// The width of every button, without the gap
+    pub(crate) fn button_widths(&self, buttons: &[&ButtonLabel]) -> Vec<u16> {
+        let widths = buttons.iter().map(|button| self.label_width(button));
+        if self.equal_button_width {
+            let widest = widths.max().unwrap_or(0);
+            vec![widest; buttons.len()]
+        } else {
+            widths.collect()
+        }
+    }
+
+    /// The columns taken by the buttons side by side, each followed by the gap
+    pub(crate) fn buttons_row_width(&self, widths: &[u16]) -> u16 {
+        widths.iter().fold(0, |row, width| {
+            row.saturating_add(width.saturating_add(self.button_gap))
+        })
+    }
+
+    /// The columns taken by the widest button and its gap
+    pub(crate) fn widest_button_width(&self, widths: &[u16]) -> u16 {
+        widths
+            .iter()
+            .max()
+            .map_or(0, |width| width.saturating_add(self.button_gap))
+    }
+
+    /// Where the buttons go in `area`, side by side on its first row or `stacked` one per row
+    pub(crate) fn button_rects(&self, area: Rect, widths: &[u16], stacked: bool) -> Vec<Rect> {
+        if stacked {
+            return widths
+                .iter()
+                .enumerate()
+                .map(|(row, &width)| {
+                    let x = match self.button_alignment {
+                        ButtonAlignment::Left => area.x,
+                        ButtonAlignment::Right => area.right().saturating_sub(width),
+                        ButtonAlignment::Center | ButtonAlignment::SpaceBetween => {
+                            area.x + area.width.saturating_sub(width) / 2
+                        }
+                    };
+                    Rect::new(x, area.y + row as u16, width, 1)
+                })
+                .collect();
+        }
+
+        let count = widths.len() as u16;
+        let labels = widths
+            .iter()
+            .fold(0u16, |labels, &width| labels.saturating_add(width));
+        let row = labels.saturating_add(self.button_gap.saturating_mul(count.saturating_sub(1)));
+        let free = area.width.saturating_sub(row);
+
+        let (mut x, gap) = match self.button_alignment {
+            ButtonAlignment::Left => (area.x, self.button_gap),
+            ButtonAlignment::Right => (area.x + free, self.button_gap),
+            ButtonAlignment::SpaceBetween if count > 1 => {
+                (area.x, area.width.saturating_sub(labels) / (count - 1))
+            }
+            _ => (area.x + area.width.saturating_sub(row) / 2, self.button_gap),
+        };
+
+        let mut rects = Vec::with_capacity(widths.len());
+        for (index, &width) in widths.iter().enumerate() {
+            // the last button of a spread row ends on the right edge despite the rounding
+            if self.button_alignment == ButtonAlignment::SpaceBetween
+                && count > 1
+                && index + 1 == widths.len()
+            {
+                x = area.right().saturating_sub(width);
+            }
+            rects.push(Rect::new(x, area.y, width, 1));
+            x = x.saturating_add(width).saturating_add(gap);
+        }
+        rects
+    }
+}
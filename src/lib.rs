@@ -1,6 +1,10 @@
 #![doc = include_str!("../README.md")]
 
+use std::collections::HashMap;
+use std::fmt::Debug;
 use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use ratatui_core::layout::Alignment;
 use ratatui_core::style::{Color, Style};
@@ -8,21 +12,224 @@ use ratatui_core::text::{Line, Text};
 use ratatui_widgets::block::Padding;
 use ratatui_widgets::borders::{BorderType, Borders};
 
+pub mod clipboard;
 mod confirm_dialog;
+mod decision;
+mod delay;
+mod details;
+mod disabled;
 pub mod helper;
+mod hints;
+mod hyperlink;
+#[cfg(feature = "crossterm")]
+mod inline;
+mod keys;
+mod layout;
+mod locale;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+mod mnemonic;
 mod popup_message;
+mod report;
+mod role;
+#[cfg(feature = "crossterm")]
+mod run;
+mod severity;
+#[cfg(feature = "serde")]
+mod spec;
+#[cfg(test)]
 mod test;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+mod theme;
+
+#[cfg(feature = "crossterm")]
+pub use inline::confirm_inline;
+#[cfg(feature = "crossterm")]
+pub use run::run_confirm;
 
 pub type Listener = (u16, Option<bool>);
 
-#[derive(Debug, Copy, Clone)]
-pub struct TryFromSliceError;
+/// How a [`ConfirmDialogState`] was closed
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DialogOutcome {
+    /// The `yes` button was chosen
+    Yes,
+    /// The `no` button was chosen
+    No,
+    /// The dialog was dismissed with `Esc`
+    Cancelled,
+}
+
+/// A [`DecisionStore`] shared between dialogs
+pub type SharedDecisionStore = Arc<Mutex<dyn DecisionStore>>;
+
+/// Remembers the answers given to dialogs whose "Don't ask again" checkbox was ticked,
+/// keyed by the dialog id
+pub trait DecisionStore: Debug + Send {
+    /// The remembered answer for the dialog `id`, if any
+    fn get(&self, id: u16) -> Option<bool>;
+
+    /// Remember `answer` for the dialog `id`
+    fn set(&mut self, id: u16, answer: bool) -> std::io::Result<()>;
+
+    /// Forget the answer for the dialog `id`
+    fn remove(&mut self, id: u16) -> std::io::Result<()>;
+}
+
+/// A [`DecisionStore`] that keeps the decisions in memory only
+#[derive(Debug, Default, Clone)]
+pub struct MemoryDecisionStore {
+    pub(crate) decisions: HashMap<u16, bool>,
+}
+
+/// A [`DecisionStore`] persisted as a JSON object in a file
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone)]
+pub struct JsonFileDecisionStore {
+    pub(crate) path: std::path::PathBuf,
+    pub(crate) decisions: HashMap<u16, bool>,
+}
+
+/// Why a string could not be parsed as a [`ButtonLabel`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ButtonLabelError {
+    /// The label is an empty string
+    Empty,
+    /// The label only contains whitespace
+    WhitespaceOnly,
+    /// The label marks more than one mnemonic, e.g. `"(Y)es (N)o"`
+    MultipleMnemonics { label: String },
+    /// The marked mnemonic is not a letter or a digit, e.g. `"(?)Help"`, or a label without a
+    /// marker has no letter or digit to pick, e.g. `"👍"`
+    InvalidMnemonic { label: String, mnemonic: String },
+}
+
+#[deprecated(note = "use `ButtonLabelError`")]
+pub type TryFromSliceError = ButtonLabelError;
 
 #[derive(Debug, Clone)]
 pub struct ButtonLabel {
     pub(crate) label: String,
     pub(crate) control: char,
     pub(crate) style: Option<Style>,
+    pub(crate) auto_mnemonic: bool,
+    pub(crate) role: Option<ButtonRole>,
+    pub(crate) is_default: bool,
+    pub(crate) disabled: bool,
+    pub(crate) disabled_reason: Option<String>,
+}
+
+/// What a button does. Without an explicit role the `yes` button is [`ButtonRole::Affirmative`]
+/// and the `no` button [`ButtonRole::Negative`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ButtonRole {
+    /// Accepts the question, e.g. "Save"
+    Affirmative,
+    /// Rejects the question, e.g. "Don't save"
+    Negative,
+    /// Dismisses the dialog, also pressed by `Esc`
+    Cancel,
+    /// Accepts the question with an action that cannot be undone, e.g. "Delete"
+    Destructive,
+}
+
+/// Where [`ConfirmDialog`] puts the affirmative button
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ButtonOrder {
+    /// `[Yes] [No]`, as on Windows
+    #[default]
+    AffirmativeFirst,
+    /// `[No] [Yes]`, as on macOS and GNOME
+    AffirmativeLast,
+}
+
+/// How [`ConfirmDialog`] places its buttons on their row
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ButtonAlignment {
+    /// Aligned with the left edge of the text
+    Left,
+    #[default]
+    Center,
+    /// Aligned with the right edge of the text
+    Right,
+    /// The first button on the left, the last one on the right
+    SpaceBetween,
+}
+
+/// A key the dialog can be bound to, independent of the terminal backend
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A character typed without `Ctrl` or `Alt`
+    Char(char),
+    /// A letter typed with `Ctrl`
+    Ctrl(char),
+    /// A letter typed with `Alt`
+    Alt(char),
+    /// A function key, e.g. `F(5)`
+    F(u8),
+    Esc,
+    Enter,
+    Tab,
+    Left,
+    Right,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+}
+
+/// A key the dialog reacts to and what it does, see [`ConfirmDialogState::key_hints`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyHint {
+    /// The key, e.g. `Esc`
+    pub key: String,
+    /// What the key does, e.g. `cancel`
+    pub action: &'static str,
+}
+
+/// The texts of the built-in buttons and checkbox in one language, see
+/// [`Translations::for_locale`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Translations {
+    pub yes: ButtonLabel,
+    pub no: ButtonLabel,
+    pub ok: ButtonLabel,
+    pub cancel: ButtonLabel,
+    pub dont_ask_again: String,
+}
+
+/// [`Translations`] keyed by locale. Lookups fall back from `de-CH` to `de` and then to English.
+#[derive(Debug, Clone)]
+pub struct Localization {
+    pub(crate) bundles: HashMap<String, Translations>,
+}
+
+/// An error in a Fluent translation resource
+#[cfg(feature = "fluent")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocalizationError {
+    /// The resource or the locale could not be parsed
+    Fluent(String),
+    /// A translated button label is not valid
+    Label { id: String, error: ButtonLabelError },
+}
+
+/// Two buttons of a [`ConfirmDialogState`] whose explicit mnemonics are the same key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MnemonicConflict {
+    /// The shared mnemonic
+    pub mnemonic: char,
+    /// The label of the `yes` button
+    pub yes_label: String,
+    /// The label of the `no` button
+    pub no_label: String,
 }
 
 #[derive(Debug, Clone)]
@@ -36,9 +243,28 @@ pub struct ConfirmDialogState {
     pub(crate) yes_button: ButtonLabel,
     pub(crate) no_button: Option<ButtonLabel>,
     pub(crate) listener: Option<Sender<Listener>>,
+    pub(crate) severity: Option<Severity>,
+    pub(crate) dont_ask_again: Option<String>,
+    pub(crate) dont_ask_again_checked: bool,
+    pub(crate) decision_store: Option<SharedDecisionStore>,
+    pub(crate) store_error: Option<Arc<std::io::Error>>,
+    pub(crate) outcome: Option<DialogOutcome>,
+    pub(crate) button_order: ButtonOrder,
+    pub(crate) triggered_disabled: Option<bool>,
+    pub(crate) enable_delay: Duration,
+    pub(crate) input_guard: Duration,
+    pub(crate) opened_at: Option<Instant>,
+    pub(crate) details: Option<Text<'static>>,
+    pub(crate) details_expanded: bool,
+    pub(crate) details_scroll: u16,
+    pub(crate) details_viewport: u16,
+    pub(crate) copy_key: Option<Key>,
+    pub(crate) copied: bool,
+    pub(crate) copy_request: Option<String>,
+    pub(crate) hyperlinks: Vec<(String, String)>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ConfirmDialog {
     pub(crate) bg: Color,
     pub(crate) borders: Borders,
@@ -47,6 +273,35 @@ pub struct ConfirmDialog {
     pub(crate) button_style: Style,
     pub(crate) selected_button_style: Style,
     pub(crate) text_style: Style,
+    pub(crate) padding: Padding,
+    pub(crate) text_alignment: Alignment,
+    pub(crate) title_alignment: Alignment,
+    pub(crate) severities: Severities,
+    pub(crate) mnemonic_display: MnemonicDisplay,
+    pub(crate) mnemonic_style: Style,
+    pub(crate) button_order: ButtonOrder,
+    pub(crate) destructive_button_style: Style,
+    pub(crate) disabled_button_style: Style,
+    pub(crate) button_alignment: ButtonAlignment,
+    pub(crate) equal_button_width: bool,
+    pub(crate) button_gap: u16,
+    pub(crate) stacked_buttons: bool,
+    pub(crate) key_hints: bool,
+    pub(crate) key_hint_style: Style,
+    pub(crate) details_height: u16,
+    pub(crate) hyperlinks: bool,
+}
+
+/// How the mnemonic of a [`ButtonLabel`] is shown
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum MnemonicDisplay {
+    /// Keep the parentheses around the mnemonic: `(Y)es`
+    #[default]
+    Parentheses,
+    /// Drop the parentheses and apply the mnemonic style to the letter: `Yes`
+    Highlight,
 }
 
 #[derive(Debug, Default)]
@@ -61,4 +316,154 @@ pub struct PopupMessage<'a, 'b> {
     pub(crate) padding: Padding,
     pub(crate) title: Line<'a>,
     pub(crate) message: Text<'b>,
+    pub(crate) severity: Option<Severity>,
+    pub(crate) severities: Severities,
+    pub(crate) copied: bool,
+    pub(crate) links: Vec<(String, String)>,
+    pub(crate) hyperlinks: bool,
+}
+
+/// Colors, borders, modifiers, padding and alignment shared by [`ConfirmDialog`] and
+/// [`PopupMessage`].
+///
+/// With the `serde` feature enabled a theme can be loaded from a TOML or JSON file,
+/// see `Theme::from_file`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Theme {
+    #[cfg_attr(feature = "serde", serde(with = "theme::color"))]
+    pub bg: Color,
+    pub borders: Borders,
+    pub border_type: BorderType,
+    #[cfg_attr(feature = "serde", serde(with = "theme::style"))]
+    pub border_style: Style,
+    #[cfg_attr(feature = "serde", serde(with = "theme::style"))]
+    pub button_style: Style,
+    #[cfg_attr(feature = "serde", serde(with = "theme::style"))]
+    pub selected_button_style: Style,
+    #[cfg_attr(feature = "serde", serde(with = "theme::style"))]
+    pub text_style: Style,
+    #[cfg_attr(feature = "serde", serde(with = "theme::padding"))]
+    pub padding: Padding,
+    pub text_alignment: Alignment,
+    pub title_alignment: Alignment,
+    pub severities: Severities,
+    pub mnemonic_display: MnemonicDisplay,
+    #[cfg_attr(feature = "serde", serde(with = "theme::style"))]
+    pub mnemonic_style: Style,
+    pub button_order: ButtonOrder,
+    #[cfg_attr(feature = "serde", serde(with = "theme::style"))]
+    pub destructive_button_style: Style,
+    #[cfg_attr(feature = "serde", serde(with = "theme::style"))]
+    pub disabled_button_style: Style,
+    pub button_alignment: ButtonAlignment,
+    pub equal_button_width: bool,
+    pub button_gap: u16,
+    pub stacked_buttons: bool,
+    pub key_hints: bool,
+    #[cfg_attr(feature = "serde", serde(with = "theme::style"))]
+    pub key_hint_style: Style,
+}
+
+/// The kind of message shown by a dialog, used to pick its border color, title icon
+/// and default buttons.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+    Success,
+    Question,
+}
+
+/// How a single [`Severity`] is presented
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeverityStyle {
+    #[cfg_attr(feature = "serde", serde(with = "theme::color"))]
+    pub color: Color,
+    pub icon: String,
+    #[cfg_attr(feature = "serde", serde(with = "theme::label"))]
+    pub yes_button: String,
+    #[cfg_attr(feature = "serde", serde(default, with = "theme::label::option"))]
+    pub no_button: Option<String>,
+}
+
+/// The [`SeverityStyle`] of every [`Severity`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Severities {
+    pub info: SeverityStyle,
+    pub warning: SeverityStyle,
+    pub error: SeverityStyle,
+    pub success: SeverityStyle,
+    pub question: SeverityStyle,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum ThemeError {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+    Json(serde_json::Error),
+    UnsupportedFormat(std::path::PathBuf),
+}
+
+/// A collection of named confirm dialogs and popups loaded from a TOML or YAML file,
+/// see [`DialogSpecs::from_file`]
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DialogSpecs {
+    pub(crate) dialogs: std::collections::BTreeMap<String, DialogSpec>,
+    pub(crate) popups: std::collections::BTreeMap<String, PopupSpec>,
+}
+
+/// The declarative definition of a [`ConfirmDialogState`]
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DialogSpec {
+    pub(crate) id: Option<u16>,
+    pub(crate) title: spec::LineSpec,
+    pub(crate) text: spec::TextSpec,
+    #[serde(default)]
+    pub(crate) buttons: Vec<String>,
+    #[serde(default)]
+    pub(crate) modal: bool,
+    #[serde(default)]
+    pub(crate) default: spec::DefaultButton,
+    pub(crate) severity: Option<Severity>,
+    pub(crate) details: Option<spec::TextSpec>,
+}
+
+/// The declarative definition of a [`PopupMessage`]
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PopupSpec {
+    pub(crate) title: spec::LineSpec,
+    pub(crate) text: spec::TextSpec,
+    pub(crate) severity: Option<Severity>,
+    pub(crate) text_alignment: Option<Alignment>,
+    pub(crate) title_alignment: Option<Alignment>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum SpecError {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+    #[cfg(feature = "yaml")]
+    Yaml(serde_norway::Error),
+    UnsupportedFormat(std::path::PathBuf),
+    NotFound(String),
+    Invalid {
+        name: String,
+        reason: String,
+    },
 }
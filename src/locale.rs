@@ -0,0 +1,246 @@
+use ratatui_core::style::Color;
+
+use crate::{
+    ButtonLabel, ButtonRole, ConfirmDialogState, Localization, Severities, SeverityStyle,
+    Translations,
+};
+
+/// The built-in bundles: locale, yes, no, ok, cancel and "Don't ask again"
+const BUILTIN: [(&str, [&str; 5]); 4] = [
+    (
+        "en",
+        ["(Y)es", "(N)o", "(O)k", "(C)ancel", "Don't ask again"],
+    ),
+    (
+        "de",
+        ["(J)a", "(N)ein", "(O)K", "(A)bbrechen", "Nicht mehr fragen"],
+    ),
+    (
+        "it",
+        ["(S)ì", "(N)o", "(O)K", "(A)nnulla", "Non chiedere più"],
+    ),
+    (
+        "fr",
+        ["(O)ui", "(N)on", "(O)K", "(A)nnuler", "Ne plus demander"],
+    ),
+];
+
+impl Translations {
+    /// Build translations from button labels such as `"(J)a"` or `"&Ja"`
+    pub fn new(
+        yes: &str,
+        no: &str,
+        ok: &str,
+        cancel: &str,
+        dont_ask_again: &str,
+    ) -> Result<Self, crate::ButtonLabelError> {
+        Ok(Translations {
+            yes: ButtonLabel::from(yes)?,
+            no: ButtonLabel::from(no)?,
+            ok: ButtonLabel::from(ok)?,
+            cancel: ButtonLabel::from(cancel)?.with_role(ButtonRole::Cancel),
+            dont_ask_again: dont_ask_again.to_string(),
+        })
+    }
+
+    /// The English translations
+    pub fn english() -> Self {
+        let [yes, no, ok, cancel, dont_ask_again] = BUILTIN[0].1;
+        Translations::new(yes, no, ok, cancel, dont_ask_again).unwrap()
+    }
+
+    /// The built-in translations for `locale`, e.g. `de`, `it-CH` or `fr_FR.UTF-8`
+    pub fn for_locale(locale: &str) -> Self {
+        Localization::new().get(locale).clone()
+    }
+
+    /// The built-in translations for the locale of the `LC_ALL`, `LC_MESSAGES` or `LANG`
+    /// environment variables
+    pub fn from_env() -> Self {
+        Translations::for_locale(&env_locale().unwrap_or_default())
+    }
+}
+
+impl Default for Translations {
+    fn default() -> Self {
+        Translations::english()
+    }
+}
+
+impl Localization {
+    /// A localization with the built-in English, German, Italian and French bundles
+    pub fn new() -> Self {
+        let bundles = BUILTIN
+            .iter()
+            .map(|(locale, [yes, no, ok, cancel, dont_ask_again])| {
+                let translations = Translations::new(yes, no, ok, cancel, dont_ask_again).unwrap();
+                (locale.to_string(), translations)
+            })
+            .collect();
+        Localization { bundles }
+    }
+
+    /// Add or replace the bundle of `locale`
+    pub fn with_bundle(&mut self, locale: &str, translations: Translations) -> &mut Self {
+        self.bundles.insert(normalize(locale), translations);
+        self
+    }
+
+    /// The locales with a bundle
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.bundles.keys().map(String::as_str)
+    }
+
+    /// The bundle of `locale`, falling back to its language and then to English
+    pub fn get(&self, locale: &str) -> &Translations {
+        let locale = normalize(locale);
+        let language = locale.split('-').next().unwrap_or_default();
+        self.bundles
+            .get(&locale)
+            .or_else(|| self.bundles.get(language))
+            .or_else(|| self.bundles.get("en"))
+            .expect("the English bundle is always available")
+    }
+
+    /// Add the bundle of `locale` from a Fluent resource defining `confirm-yes`, `confirm-no`,
+    /// `confirm-ok`, `confirm-cancel` and `confirm-dont-ask-again`. Missing messages keep the
+    /// text of the bundle `locale` falls back to.
+    #[cfg(feature = "fluent")]
+    pub fn with_fluent(
+        &mut self,
+        locale: &str,
+        source: &str,
+    ) -> Result<&mut Self, crate::LocalizationError> {
+        use crate::LocalizationError;
+
+        let language = locale
+            .replace('_', "-")
+            .split('.')
+            .next()
+            .unwrap_or_default()
+            .parse::<unic_langid::LanguageIdentifier>()
+            .map_err(|err| {
+                LocalizationError::Fluent(format!("invalid locale `{locale}`: {err}"))
+            })?;
+        let resource = fluent_bundle::FluentResource::try_new(source.to_string())
+            .map_err(|(_, errors)| LocalizationError::Fluent(join_errors(&errors)))?;
+
+        let mut bundle = fluent_bundle::FluentBundle::new(vec![language]);
+        bundle.set_use_isolating(false);
+        bundle
+            .add_resource(resource)
+            .map_err(|errors| LocalizationError::Fluent(join_errors(&errors)))?;
+
+        let message = |id: &str| -> Result<Option<String>, LocalizationError> {
+            let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) else {
+                return Ok(None);
+            };
+            let mut errors = Vec::new();
+            let value = bundle.format_pattern(pattern, None, &mut errors);
+            if !errors.is_empty() {
+                return Err(LocalizationError::Fluent(join_errors(&errors)));
+            }
+            Ok(Some(value.into_owned()))
+        };
+        let label = |id: &str, fallback: &ButtonLabel| -> Result<ButtonLabel, LocalizationError> {
+            match message(id)? {
+                Some(value) => ButtonLabel::from(value).map_err(|error| LocalizationError::Label {
+                    id: id.to_string(),
+                    error,
+                }),
+                None => Ok(fallback.clone()),
+            }
+        };
+
+        let fallback = self.get(locale).clone();
+        let translations = Translations {
+            yes: label("confirm-yes", &fallback.yes)?,
+            no: label("confirm-no", &fallback.no)?,
+            ok: label("confirm-ok", &fallback.ok)?,
+            cancel: label("confirm-cancel", &fallback.cancel)?.with_role(ButtonRole::Cancel),
+            dont_ask_again: message("confirm-dont-ask-again")?.unwrap_or(fallback.dont_ask_again),
+        };
+        Ok(self.with_bundle(locale, translations))
+    }
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Localization::new()
+    }
+}
+
+impl Severities {
+    /// The default severities with the buttons of `translations`
+    pub fn localized(translations: &Translations) -> Self {
+        let ok = translations.ok.label.as_str();
+        let cancel = translations.cancel.label.as_str();
+        Severities {
+            info: SeverityStyle::new(Color::Blue, "ℹ", ok, None),
+            warning: SeverityStyle::new(Color::Yellow, "⚠", ok, Some(cancel)),
+            error: SeverityStyle::new(Color::Red, "✖", ok, None),
+            success: SeverityStyle::new(Color::Green, "✔", ok, None),
+            question: SeverityStyle::new(
+                Color::Cyan,
+                "?",
+                translations.yes.label.as_str(),
+                Some(translations.no.label.as_str()),
+            ),
+        }
+    }
+}
+
+impl ConfirmDialogState {
+    /// Replace the buttons with the `yes` and `no` labels of `translations`, keeping a missing
+    /// `no` button missing, and translate the "Don't ask again" checkbox
+    pub fn with_translations(&mut self, translations: &Translations) -> &mut Self {
+        self.yes_button = translations.yes.clone();
+        if self.no_button.is_some() {
+            self.no_button = Some(translations.no.clone());
+        }
+        self.dont_ask_again = Some(translations.dont_ask_again.clone());
+        self.resolve_mnemonics();
+        self
+    }
+}
+
+/// `de_CH.UTF-8` and `de-ch` both become `de-ch`
+fn normalize(locale: &str) -> String {
+    locale
+        .split(['.', '@'])
+        .next()
+        .unwrap_or_default()
+        .replace('_', "-")
+        .to_ascii_lowercase()
+}
+
+fn env_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty() && value != "C" && value != "POSIX")
+}
+
+#[cfg(feature = "fluent")]
+fn join_errors<E: std::fmt::Display>(errors: &[E]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(feature = "fluent")]
+impl std::fmt::Display for crate::LocalizationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            crate::LocalizationError::Fluent(err) => write!(f, "invalid translations: {err}"),
+            crate::LocalizationError::Label { id, error } => {
+                write!(f, "invalid translation `{id}`: {error}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "fluent")]
+impl std::error::Error for crate::LocalizationError {}
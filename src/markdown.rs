@@ -0,0 +1,176 @@
+//! Convert a Markdown subset to styled text for the dialogs: **bold**, *italic*, `inline code`,
+//! headings, bullet and numbered lists and code blocks. Other elements keep their text.
+//!
+//! ```
+//! use tui_confirm_dialog::markdown::to_text;
+//!
+//! let text = to_text("# Cleanup\n\nThis will delete:\n\n- the **build** directory\n- `target`");
+//! assert_eq!(6, text.lines.len());
+//! ```
+use std::mem;
+
+use pulldown_cmark::{Event, HeadingLevel, Parser, Tag, TagEnd};
+use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::text::{Line, Span, Text};
+
+/// Style of inline code and code blocks
+const CODE_STYLE: Style = Style::new().fg(Color::Yellow);
+
+/// Convert `markdown` to a text for [`ConfirmDialogState::with_text`](crate::ConfirmDialogState::with_text)
+/// or [`PopupMessage::new`](crate::PopupMessage::new). Paragraphs are separated by a blank line,
+/// soft line breaks become spaces and the lines are left to the dialog to wrap.
+pub fn to_text(markdown: &str) -> Text<'static> {
+    let mut converter = Converter::default();
+    for event in Parser::new(markdown) {
+        converter.event(event);
+    }
+    converter.flush();
+    Text::from(converter.lines)
+}
+
+#[derive(Default)]
+struct Converter {
+    lines: Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    styles: Vec<Style>,
+    /// The next number of each open list, `None` for bullet lists
+    lists: Vec<Option<u64>>,
+    /// The width of the current list item marker, to indent its next lines
+    indent: usize,
+}
+
+impl Converter {
+    fn event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                let style = self.style();
+                self.push_text(&text, style);
+            }
+            Event::Code(code) => {
+                let style = self.style().patch(CODE_STYLE);
+                self.push(Span::styled(code.into_string(), style));
+            }
+            Event::Html(html) | Event::InlineHtml(html) => {
+                let style = self.style();
+                self.push_text(&html, style);
+            }
+            Event::SoftBreak => self.push(Span::raw(" ")),
+            Event::HardBreak => self.flush(),
+            Event::Rule => {
+                self.block();
+                self.lines.push(Line::raw("───"));
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph => self.block(),
+            Tag::Heading { level, .. } => {
+                self.block();
+                let style = match level {
+                    HeadingLevel::H1 => Style::new().bold().underlined(),
+                    _ => Style::new().bold(),
+                };
+                self.styles.push(style);
+            }
+            Tag::CodeBlock(_) => {
+                self.block();
+                self.styles.push(CODE_STYLE);
+            }
+            Tag::List(start) => {
+                self.flush();
+                self.block();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush();
+                let depth = self.lists.len().saturating_sub(1);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "• ".to_string(),
+                };
+                let prefix = format!("{}{marker}", "  ".repeat(depth));
+                self.indent = prefix.chars().count();
+                self.spans.push(Span::raw(prefix));
+            }
+            Tag::Emphasis => self
+                .styles
+                .push(Style::new().add_modifier(Modifier::ITALIC)),
+            Tag::Strong => self.styles.push(Style::new().add_modifier(Modifier::BOLD)),
+            Tag::Strikethrough => self
+                .styles
+                .push(Style::new().add_modifier(Modifier::CROSSED_OUT)),
+            Tag::Link { .. } => self
+                .styles
+                .push(Style::new().add_modifier(Modifier::UNDERLINED)),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::Item => self.flush(),
+            TagEnd::Heading(_) | TagEnd::CodeBlock => {
+                self.flush();
+                self.styles.pop();
+            }
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+                self.indent = 0;
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Link => {
+                self.styles.pop();
+            }
+            _ => {}
+        }
+    }
+
+    /// The style of the innermost open elements
+    fn style(&self) -> Style {
+        self.styles
+            .iter()
+            .fold(Style::new(), |style, inner| style.patch(*inner))
+    }
+
+    /// Separate a top level block from the previous one with a blank line
+    fn block(&mut self) {
+        if self.lists.is_empty() && (!self.lines.is_empty() || !self.spans.is_empty()) {
+            self.flush();
+            self.lines.push(Line::default());
+        }
+    }
+
+    /// Add `text`, which only has several lines in code and HTML blocks
+    fn push_text(&mut self, text: &str, style: Style) {
+        if !text.contains('\n') {
+            self.push(Span::styled(text.to_string(), style));
+            return;
+        }
+        for line in text.lines() {
+            self.push(Span::styled(line.to_string(), style));
+            self.flush();
+        }
+    }
+
+    fn push(&mut self, span: Span<'static>) {
+        // the next lines of a list item line up with its text
+        if self.spans.is_empty() && !self.lists.is_empty() && self.indent > 0 {
+            self.spans.push(Span::raw(" ".repeat(self.indent)));
+        }
+        self.spans.push(span);
+    }
+
+    fn flush(&mut self) {
+        if !self.spans.is_empty() {
+            self.lines.push(Line::from(mem::take(&mut self.spans)));
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::fmt;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::confirm_dialog::{is_mnemonic, mnemonic_char};
+use crate::{ButtonLabel, ConfirmDialogState, MnemonicConflict};
+
+impl ButtonLabel {
+    /// Whether the mnemonic was picked automatically because the label did not mark one
+    pub fn has_auto_mnemonic(&self) -> bool {
+        self.auto_mnemonic
+    }
+
+    /// Move an automatic mnemonic to the first letter or digit of the label not in `taken`.
+    /// Returns `false` when the mnemonic is explicit or no free character is left.
+    pub(crate) fn reassign_mnemonic(&mut self, taken: &[char]) -> bool {
+        if !self.auto_mnemonic {
+            return false;
+        }
+
+        // the label without the marker of the current mnemonic: "(N)o" gives "No"
+        let Some(text) = self
+            .mnemonic_parts()
+            .map(|(before, mnemonic, after)| format!("{before}{mnemonic}{after}"))
+        else {
+            return false;
+        };
+
+        let free = text.grapheme_indices(true).find_map(|(index, grapheme)| {
+            mnemonic_char(grapheme)
+                .filter(|chr| is_mnemonic(grapheme) && !taken.contains(chr))
+                .map(|chr| (index, grapheme, chr))
+        });
+
+        match free {
+            Some((index, grapheme, chr)) => {
+                self.label = format!(
+                    "{}({grapheme}){}",
+                    &text[..index],
+                    &text[index + grapheme.len()..]
+                );
+                self.control = chr;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl ConfirmDialogState {
+    /// Set both buttons at once, failing if their explicit mnemonics are the same key.
+    /// Automatic mnemonics are reassigned to a free character of their label.
+    pub fn with_buttons(
+        &mut self,
+        yes: ButtonLabel,
+        no: Option<ButtonLabel>,
+    ) -> Result<&mut Self, MnemonicConflict> {
+        let previous = (
+            std::mem::replace(&mut self.yes_button, yes),
+            std::mem::replace(&mut self.no_button, no),
+        );
+        self.resolve_mnemonics();
+
+        if let Some(conflict) = self.mnemonic_conflict() {
+            (self.yes_button, self.no_button) = previous;
+            return Err(conflict);
+        }
+        Ok(self)
+    }
+
+    /// The mnemonic shared by both buttons, if any. Such a key triggers neither button.
+    pub fn mnemonic_conflict(&self) -> Option<MnemonicConflict> {
+        let no_button = self.no_button.as_ref()?;
+        (no_button.control == self.yes_button.control).then(|| MnemonicConflict {
+            mnemonic: self.yes_button.control,
+            yes_label: self.yes_button.label.clone(),
+            no_label: no_button.label.clone(),
+        })
+    }
+
+    /// Reassign an automatic mnemonic clashing with the other button, preferring the `no` one.
+    /// The automatic mnemonics are first moved back to their usual place, so the result only
+    /// depends on the current buttons and not on the ones they replaced.
+    pub(crate) fn resolve_mnemonics(&mut self) {
+        self.yes_button.reassign_mnemonic(&[]);
+        let Some(no_button) = self.no_button.as_mut() else {
+            return;
+        };
+        no_button.reassign_mnemonic(&[]);
+        if no_button.control != self.yes_button.control {
+            return;
+        }
+
+        if !no_button.reassign_mnemonic(&[self.yes_button.control]) {
+            self.yes_button.reassign_mnemonic(&[no_button.control]);
+        }
+    }
+}
+
+impl fmt::Display for MnemonicConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buttons `{}` and `{}` share the mnemonic `{}`",
+            self.yes_label, self.no_label, self.mnemonic
+        )
+    }
+}
+
+impl Error for MnemonicConflict {}
@@ -9,6 +9,7 @@ use ratatui_widgets::borders::{BorderType, Borders};
 use ratatui_widgets::clear::Clear;
 use ratatui_widgets::paragraph::Paragraph;
 
+use crate::clipboard::COPIED;
 use crate::PopupMessage;
 
 impl<'a, 'b> PopupMessage<'a, 'b> {
@@ -28,6 +29,11 @@ impl<'a, 'b> PopupMessage<'a, 'b> {
             padding: Padding::uniform(2),
             title: title.into(),
             message: message.into(),
+            severity: None,
+            severities: Default::default(),
+            copied: false,
+            links: Vec::new(),
+            hyperlinks: super::hyperlink::supported(),
         }
     }
 }
@@ -75,44 +81,102 @@ impl PopupMessage<'_, '_> {
 }
 
 impl Widget for PopupMessage<'_, '_> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let horizontal_padding = self.padding.left + self.padding.right;
-        let vertical_padding = self.padding.top + self.padding.bottom;
+    /// Renders the popup centered in `area`, shrinking the padding and cutting the title with an
+    /// ellipsis when `area` is too small
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        self.padding = super::helper::clamp_padding(self.padding, area);
 
-        let block = Block::default()
-            .title(self.title)
-            .title_alignment(self.title_alignment)
-            .borders(self.borders)
-            .border_type(self.border_type)
-            .border_style(self.border_style)
-            .padding(self.padding)
-            .bg(self.bg);
+        let horizontal_padding = self.padding.left.saturating_add(self.padding.right);
+        let vertical_padding = self.padding.top.saturating_add(self.padding.bottom);
 
-        let mut width = self
+        let text_width = self
             .message
             .lines
             .iter()
-            .max_by(|a, b| a.width().cmp(&b.width()))
-            .map(|line| line.width() + (horizontal_padding as usize) + 2)
-            .unwrap() as u16;
-
+            .map(Line::width)
+            .max()
+            .unwrap_or(0)
+            .min(u16::MAX as usize) as u16;
+
+        let mut width = text_width.saturating_add(horizontal_padding.saturating_add(2));
+        if self.copied {
+            width = width.max(Line::from(COPIED).width() as u16 + 2);
+        }
         if width % 2 == 1 {
             width = width.saturating_add(1);
         }
 
-        let mut height = (self.message.lines.len() + vertical_padding as usize) as u16 + 1;
+        let text_height = self.message.lines.len().min(u16::MAX as usize) as u16;
+        let mut height = text_height.saturating_add(vertical_padding.saturating_add(1));
         if height % 2 == 1 {
             height = height.saturating_add(1);
         }
 
+        let centered_area =
+            super::helper::centered_rect_with_size(width, height.saturating_add(1), area);
+
+        let border = |side: Borders| u16::from(self.borders.contains(side));
+        let inner_width = centered_area
+            .width
+            .saturating_sub(border(Borders::LEFT) + border(Borders::RIGHT));
+        let inner_height = centered_area
+            .height
+            .saturating_sub(border(Borders::TOP) + border(Borders::BOTTOM));
+
+        let (left, right) = super::helper::shrink_padding(
+            (self.padding.left, self.padding.right),
+            (0, 0),
+            text_width
+                .saturating_add(horizontal_padding)
+                .saturating_sub(inner_width),
+        );
+        let (top, bottom) = super::helper::shrink_padding(
+            (self.padding.top, self.padding.bottom),
+            (0, 0),
+            text_height
+                .saturating_add(vertical_padding)
+                .saturating_sub(inner_height),
+        );
+
+        let mut title = self.title;
+        let mut border_style = self.border_style;
+        if let Some(severity) = self.severity {
+            let severity_style = self.severities.get(severity);
+            title = severity_style.decorate_title(title);
+            border_style = border_style.fg(severity_style.color);
+        }
+
+        let mut block = Block::default()
+            .title(super::helper::truncate_line(title, inner_width))
+            .title_alignment(self.title_alignment)
+            .borders(self.borders)
+            .border_type(self.border_type)
+            .border_style(border_style)
+            .padding(Padding::new(left, right, top, bottom))
+            .bg(self.bg);
+        if self.copied {
+            block = block.title_bottom(
+                super::helper::truncate_line(Line::from(COPIED), inner_width)
+                    .style(self.text_style)
+                    .right_aligned(),
+            );
+        }
+
+        let links = if self.hyperlinks {
+            self.links
+        } else {
+            Vec::new()
+        };
         let paragraph = Paragraph::new(self.message)
             .style(self.text_style)
             .alignment(self.text_alignment)
             .block(block);
 
-        let centered_area = super::helper::centered_rect_with_size(width, height + 1, area);
-
-        Clear::default().render(centered_area, buf);
+        Clear.render(centered_area, buf);
         paragraph.render(centered_area, buf);
+        super::hyperlink::render_hyperlinks(centered_area, buf, &links);
     }
 }
@@ -0,0 +1,60 @@
+use std::error::Error;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Text};
+
+use crate::{ConfirmDialogState, PopupMessage, Severity};
+
+impl<'a> PopupMessage<'a, 'static> {
+    /// An error popup showing the message of `error` in bold, followed by its
+    /// [`source`](Error::source) chain
+    pub fn from_error<T>(title: T, error: &dyn Error) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        PopupMessage::new(title, error_text(error)).severity(Severity::Error)
+    }
+}
+
+impl ConfirmDialogState {
+    /// An error dialog showing the message of `error` in bold, followed by its
+    /// [`source`](Error::source) chain. The buttons are the ones of [`Severity::Error`] and can
+    /// be replaced to ask a question, e.g. "Retry?".
+    pub fn from_error<T>(id: u16, title: T, error: &dyn Error) -> Self
+    where
+        T: Into<Line<'static>>,
+    {
+        let mut state = ConfirmDialogState::new(id, title, error_text(error));
+        state.with_severity(Severity::Error);
+        state
+    }
+}
+
+/// The message of `error`, then a `Caused by:` list of its sources, one level of indentation
+/// per source
+pub(crate) fn error_text(error: &dyn Error) -> Text<'static> {
+    let mut lines = error
+        .to_string()
+        .lines()
+        .map(|line| Line::styled(line.to_string(), Style::new().bold()))
+        .collect::<Vec<_>>();
+
+    let mut source = error.source();
+    if source.is_some() {
+        lines.push(Line::default());
+        lines.push(Line::from("Caused by:"));
+    }
+
+    let mut depth = 1;
+    while let Some(error) = source {
+        let indent = "  ".repeat(depth);
+        for (index, line) in error.to_string().lines().enumerate() {
+            let bullet = if index == 0 { "• " } else { "  " };
+            lines.push(Line::from(format!("{indent}{bullet}{line}")));
+        }
+        source = error.source();
+        depth += 1;
+    }
+
+    Text::from(lines)
+}
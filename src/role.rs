@@ -0,0 +1,126 @@
+use ratatui_core::style::Style;
+
+use crate::{ButtonLabel, ButtonOrder, ButtonRole, ConfirmDialog, ConfirmDialogState};
+
+impl ButtonRole {
+    /// Whether the button accepts the question, placed according to the [`ButtonOrder`]
+    pub fn is_affirmative(self) -> bool {
+        matches!(self, ButtonRole::Affirmative | ButtonRole::Destructive)
+    }
+}
+
+impl ButtonOrder {
+    /// The convention of the platform the crate is compiled for
+    pub const fn platform() -> Self {
+        if cfg!(any(target_os = "macos", target_os = "linux")) {
+            ButtonOrder::AffirmativeLast
+        } else {
+            ButtonOrder::AffirmativeFirst
+        }
+    }
+}
+
+impl ButtonLabel {
+    /// Set the button role
+    pub fn with_role(mut self, role: ButtonRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// The explicit button role, if any
+    pub fn role(&self) -> Option<ButtonRole> {
+        self.role
+    }
+
+    /// Make the button the one selected when the dialog opens, so `Enter` presses it
+    pub fn with_default(mut self, is_default: bool) -> Self {
+        self.is_default = is_default;
+        self
+    }
+
+    /// Check if the button is selected when the dialog opens
+    pub fn is_default(&self) -> bool {
+        self.is_default
+    }
+}
+
+impl ConfirmDialogState {
+    /// Set the role of the `yes` button
+    pub fn with_yes_role(&mut self, role: ButtonRole) -> &mut Self {
+        self.yes_button.role = Some(role);
+        self
+    }
+
+    /// Set the role of the `no` button, if there is one
+    pub fn with_no_role(&mut self, role: ButtonRole) -> &mut Self {
+        if let Some(button) = self.no_button.as_mut() {
+            button.role = Some(role);
+        }
+        self
+    }
+
+    /// The role of the `yes` button, [`ButtonRole::Affirmative`] unless set
+    pub fn yes_role(&self) -> ButtonRole {
+        self.yes_button.role.unwrap_or(ButtonRole::Affirmative)
+    }
+
+    /// The role of the `no` button, [`ButtonRole::Negative`] unless set
+    pub fn no_role(&self) -> Option<ButtonRole> {
+        self.no_button
+            .as_ref()
+            .map(|button| button.role.unwrap_or(ButtonRole::Negative))
+    }
+
+    /// The answer of the button with the [`ButtonRole::Cancel`] role, pressed by `Esc`
+    #[cfg(feature = "crossterm")]
+    pub(crate) fn cancel_answer(&self) -> Option<bool> {
+        if self.yes_role() == ButtonRole::Cancel {
+            Some(true)
+        } else if self.no_role() == Some(ButtonRole::Cancel) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Select the button marked with [`ButtonLabel::with_default`], if any, skipping disabled ones
+    pub(crate) fn select_default_button(&mut self) {
+        if self.yes_button.is_default {
+            self.yes_selected = true;
+        } else if self
+            .no_button
+            .as_ref()
+            .is_some_and(|button| button.is_default)
+        {
+            self.yes_selected = false;
+        }
+        // never start on a disabled button when the other one can be pressed
+        if !self.is_enabled(self.yes_selected) && self.is_enabled(!self.yes_selected) {
+            self.yes_selected = !self.yes_selected;
+        }
+    }
+
+    /// Whether the `yes` button is shown first (left or top) with the last rendered order
+    pub(crate) fn yes_first(&self) -> bool {
+        let yes_affirmative = self.yes_role().is_affirmative();
+        let no_affirmative = self.no_role().is_some_and(ButtonRole::is_affirmative);
+        if yes_affirmative == no_affirmative {
+            return true;
+        }
+        yes_affirmative == (self.button_order == ButtonOrder::AffirmativeFirst)
+    }
+}
+
+impl ConfirmDialog {
+    /// Set where the affirmative button goes
+    pub fn button_order(mut self, order: ButtonOrder) -> Self {
+        self.button_order = order;
+        self
+    }
+
+    /// Set the style patched over the style of [`ButtonRole::Destructive`] buttons
+    pub fn destructive_button_style(mut self, style: Style) -> Self {
+        self.destructive_button_style = style;
+        self
+    }
+}
@@ -0,0 +1,94 @@
+use std::io;
+
+use ratatui::backend::Backend;
+use ratatui::buffer::CellDiffOption;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use ratatui::Terminal;
+
+use crate::clipboard::copy_to_terminal;
+use crate::helper::centered_rect_with_size;
+use crate::{ConfirmDialog, ConfirmDialogState, DialogOutcome, Key};
+
+/// Raw mode turns `Ctrl+C` into a key, which then cancels the dialog
+const ABORT_KEY: Key = Key::Ctrl('c');
+
+/// Show `state` with `dialog` on top of the last frame drawn on `terminal` and block, reading
+/// the crossterm events, until the dialog is closed.
+///
+/// The state is opened if needed, so a remembered "Don't ask again" decision returns
+/// immediately without drawing. The listener of `state`, if any, is notified as usual.
+/// While an [enable delay](ConfirmDialogState::with_enable_delay) counts down, the dialog is
+/// redrawn every second. `Ctrl+C` cancels the dialog, even a modal one, unless it is the
+/// [copy key](ConfirmDialogState::with_copy_key).
+///
+/// Only the cells covered by the dialog are redrawn, everything else on screen is left untouched
+/// until the next regular `terminal.draw`. A terminal resize clears the screen, in which case
+/// only the dialog is drawn again.
+pub fn run_confirm<B>(
+    terminal: &mut Terminal<B>,
+    state: &mut ConfirmDialogState,
+    dialog: ConfirmDialog,
+) -> io::Result<DialogOutcome>
+where
+    B: Backend,
+    io::Error: From<B::Error>,
+{
+    if !state.is_opened() {
+        state.open();
+    }
+
+    event_loop(state, |state| {
+        terminal.draw(|f| {
+            let area = f.area();
+            let size = dialog.size(state);
+            let dialog_area = centered_rect_with_size(size.width, size.height, area);
+
+            let buf = f.buffer_mut();
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if !dialog_area.contains((x, y).into()) {
+                        buf[(x, y)].set_diff_option(CellDiffOption::Skip);
+                    }
+                }
+            }
+
+            f.render_stateful_widget(dialog.clone(), area, state);
+        })?;
+        Ok(())
+    })?;
+
+    Ok(state.outcome().unwrap_or(DialogOutcome::Cancelled))
+}
+
+/// Draw `state` with `draw` and handle the crossterm key events until the dialog is closed
+pub(crate) fn event_loop<F>(state: &mut ConfirmDialogState, mut draw: F) -> io::Result<()>
+where
+    F: FnMut(&mut ConfirmDialogState) -> io::Result<()>,
+{
+    while state.is_opened() {
+        draw(state)?;
+        // wake up to redraw the countdown of a delayed button
+        if let Some(timeout) = state.next_tick() {
+            if !event::poll(timeout)? {
+                continue;
+            }
+        }
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press
+                && ABORT_KEY.matches(&key)
+                && state.copy_key != Some(ABORT_KEY)
+            {
+                state.opened = false;
+                state.send_close_message(None);
+                continue;
+            }
+            state.handle(&key);
+            if let Some(text) = state.take_copy_request() {
+                // a failed copy is not worth aborting the dialog
+                let copied = copy_to_terminal(&text).is_ok();
+                state.with_copied(copied);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use ratatui_core::style::{Color, Style};
+use ratatui_core::text::{Line, Span};
+
+use crate::{
+    ButtonLabel, ConfirmDialog, ConfirmDialogState, PopupMessage, Severities, Severity,
+    SeverityStyle, Theme,
+};
+
+impl SeverityStyle {
+    pub fn new<I, Y>(color: Color, icon: I, yes_button: Y, no_button: Option<&str>) -> Self
+    where
+        I: Into<String>,
+        Y: Into<String>,
+    {
+        SeverityStyle {
+            color,
+            icon: icon.into(),
+            yes_button: yes_button.into(),
+            no_button: no_button.map(|label| label.to_string()),
+        }
+    }
+
+    /// Prepend the icon to `title`, colored with the severity color
+    pub(crate) fn decorate_title<'a>(&self, title: Line<'a>) -> Line<'a> {
+        if self.icon.is_empty() {
+            return title;
+        }
+
+        let starts_with_space = title
+            .spans
+            .first()
+            .is_some_and(|span| span.content.starts_with(char::is_whitespace));
+
+        let icon = if starts_with_space {
+            format!(" {}", self.icon)
+        } else {
+            format!("{} ", self.icon)
+        };
+
+        let mut spans = vec![Span::styled(icon, Style::new().fg(self.color))];
+        spans.extend(title.spans);
+
+        Line {
+            spans,
+            style: title.style,
+            alignment: title.alignment,
+        }
+    }
+}
+
+impl Default for Severities {
+    /// The severities with the English buttons, see [`Severities::localized`]
+    fn default() -> Self {
+        Severities::localized(&crate::Translations::english())
+    }
+}
+
+impl Severities {
+    pub fn get(&self, severity: Severity) -> &SeverityStyle {
+        match severity {
+            Severity::Info => &self.info,
+            Severity::Warning => &self.warning,
+            Severity::Error => &self.error,
+            Severity::Success => &self.success,
+            Severity::Question => &self.question,
+        }
+    }
+
+    pub fn get_mut(&mut self, severity: Severity) -> &mut SeverityStyle {
+        match severity {
+            Severity::Info => &mut self.info,
+            Severity::Warning => &mut self.warning,
+            Severity::Error => &mut self.error,
+            Severity::Success => &mut self.success,
+            Severity::Question => &mut self.question,
+        }
+    }
+}
+
+impl ConfirmDialogState {
+    /// Set the dialog severity and replace the buttons with the severity defaults of
+    /// the default [`Theme`]
+    pub fn with_severity(&mut self, severity: Severity) -> &mut Self {
+        self.with_themed_severity(severity, &Theme::default())
+    }
+
+    /// Set the dialog severity and replace the buttons with the severity defaults of `theme`
+    pub fn with_themed_severity(&mut self, severity: Severity, theme: &Theme) -> &mut Self {
+        let style = theme.severities.get(severity);
+        self.severity = Some(severity);
+        self.yes_button = ButtonLabel::from(style.yes_button.as_str())
+            .unwrap_or_else(|_| ButtonLabel::new("(O)k", 'o'));
+        self.no_button = style
+            .no_button
+            .as_deref()
+            .and_then(|label| ButtonLabel::from(label).ok());
+        self.resolve_mnemonics();
+        self
+    }
+
+    /// Get the dialog severity
+    pub fn severity(&self) -> Option<Severity> {
+        self.severity
+    }
+}
+
+impl ConfirmDialog {
+    /// Set the colors and icons used for each dialog severity
+    pub fn severities(mut self, severities: Severities) -> Self {
+        self.severities = severities;
+        self
+    }
+}
+
+impl PopupMessage<'_, '_> {
+    /// Set the popup severity, which picks the border color and title icon
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Set the colors and icons used for each popup severity
+    pub fn severities(mut self, severities: Severities) -> Self {
+        self.severities = severities;
+        self
+    }
+}
@@ -0,0 +1,354 @@
+use std::fmt;
+use std::path::Path;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Span, Text};
+use serde::Deserialize;
+
+use crate::{
+    ButtonLabel, ConfirmDialogState, DialogSpec, DialogSpecs, PopupMessage, PopupSpec, SpecError,
+};
+
+/// A line given either as a plain string or as a list of styled spans
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum LineSpec {
+    Plain(String),
+    Spans(Vec<SpanSpec>),
+}
+
+/// A text given either as a plain (possibly multi-line) string or as a list of lines
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum TextSpec {
+    Plain(String),
+    Lines(Vec<LineSpec>),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SpanSpec {
+    text: String,
+    #[serde(default, with = "crate::theme::style")]
+    style: Style,
+    #[serde(default)]
+    link: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum DefaultButton {
+    #[default]
+    Yes,
+    No,
+}
+
+impl LineSpec {
+    fn to_line(&self) -> Line<'static> {
+        match self {
+            LineSpec::Plain(value) => Line::raw(value.clone()),
+            LineSpec::Spans(spans) => Line::from(
+                spans
+                    .iter()
+                    .map(|span| Span::styled(span.text.clone(), span.style))
+                    .collect::<Vec<_>>(),
+            ),
+        }
+    }
+
+    /// The `(text, url)` of the spans with a link
+    fn links(&self) -> Vec<(String, String)> {
+        match self {
+            LineSpec::Plain(_) => Vec::new(),
+            LineSpec::Spans(spans) => spans
+                .iter()
+                .filter_map(|span| Some((span.text.clone(), span.link.clone()?)))
+                .collect(),
+        }
+    }
+
+    fn is_blank(&self) -> bool {
+        match self {
+            LineSpec::Plain(value) => value.trim().is_empty(),
+            LineSpec::Spans(spans) => spans.iter().all(|span| span.text.trim().is_empty()),
+        }
+    }
+}
+
+impl TextSpec {
+    fn to_text(&self) -> Text<'static> {
+        match self {
+            TextSpec::Plain(value) => Text::raw(value.clone()),
+            TextSpec::Lines(lines) => {
+                Text::from(lines.iter().map(LineSpec::to_line).collect::<Vec<_>>())
+            }
+        }
+    }
+
+    fn links(&self) -> Vec<(String, String)> {
+        match self {
+            TextSpec::Plain(_) => Vec::new(),
+            TextSpec::Lines(lines) => lines.iter().flat_map(LineSpec::links).collect(),
+        }
+    }
+
+    fn is_blank(&self) -> bool {
+        match self {
+            TextSpec::Plain(value) => value.trim().is_empty(),
+            TextSpec::Lines(lines) => lines.iter().all(LineSpec::is_blank),
+        }
+    }
+}
+
+impl DialogSpecs {
+    /// Parse the specs from a TOML document
+    pub fn from_toml_str(value: &str) -> Result<Self, SpecError> {
+        let specs: DialogSpecs = toml::from_str(value).map_err(SpecError::Toml)?;
+        specs.validate()?;
+        Ok(specs)
+    }
+
+    /// Parse the specs from a YAML document
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml_str(value: &str) -> Result<Self, SpecError> {
+        let specs: DialogSpecs = serde_norway::from_str(value).map_err(SpecError::Yaml)?;
+        specs.validate()?;
+        Ok(specs)
+    }
+
+    /// Load the specs from a `.toml` file, or a `.yaml`/`.yml` file when the `yaml` feature is enabled.
+    /// The format is picked from the file extension.
+    pub fn from_file<P>(path: P) -> Result<Self, SpecError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+
+        match extension.as_deref() {
+            Some("toml") => Self::from_toml_str(&std::fs::read_to_string(path)?),
+            #[cfg(feature = "yaml")]
+            Some("yaml" | "yml") => Self::from_yaml_str(&std::fs::read_to_string(path)?),
+            _ => Err(SpecError::UnsupportedFormat(path.to_path_buf())),
+        }
+    }
+
+    /// Names of the confirm dialogs
+    pub fn dialog_names(&self) -> impl Iterator<Item = &str> {
+        self.dialogs.keys().map(String::as_str)
+    }
+
+    /// Names of the popups
+    pub fn popup_names(&self) -> impl Iterator<Item = &str> {
+        self.popups.keys().map(String::as_str)
+    }
+
+    /// Get the definition of the confirm dialog `name`
+    pub fn dialog(&self, name: &str) -> Option<&DialogSpec> {
+        self.dialogs.get(name)
+    }
+
+    /// Get the definition of the popup `name`
+    pub fn popup(&self, name: &str) -> Option<&PopupSpec> {
+        self.popups.get(name)
+    }
+
+    /// Build the state of the confirm dialog `name`
+    pub fn confirm_dialog(&self, name: &str) -> Result<ConfirmDialogState, SpecError> {
+        self.dialog(name)
+            .ok_or_else(|| SpecError::NotFound(name.to_string()))?
+            .to_state(name)
+    }
+
+    /// Build the popup `name`
+    pub fn popup_message(&self, name: &str) -> Result<PopupMessage<'static, 'static>, SpecError> {
+        Ok(self
+            .popup(name)
+            .ok_or_else(|| SpecError::NotFound(name.to_string()))?
+            .to_popup())
+    }
+
+    /// Check every definition, and that no two dialogs share an id since the id keys the
+    /// remembered "Don't ask again" decisions
+    fn validate(&self) -> Result<(), SpecError> {
+        let mut ids = std::collections::HashMap::new();
+        for (name, dialog) in &self.dialogs {
+            let state = dialog.to_state(name)?;
+            if let Some(other) = ids.insert(state.id, name) {
+                let reason = if dialog.id.is_some() {
+                    format!("id {} is already used by dialog `{other}`", state.id)
+                } else {
+                    format!(
+                        "the id {} derived from its name is already used by dialog `{other}`, set an explicit `id`",
+                        state.id
+                    )
+                };
+                return Err(SpecError::Invalid {
+                    name: name.to_string(),
+                    reason,
+                });
+            }
+        }
+        for (name, popup) in &self.popups {
+            popup.validate(name)?;
+        }
+        Ok(())
+    }
+}
+
+impl DialogSpec {
+    /// Build the dialog state. Without an explicit `id` a stable id is derived from `name`,
+    /// so remembered decisions survive restarts.
+    pub fn to_state(&self, name: &str) -> Result<ConfirmDialogState, SpecError> {
+        let invalid = |reason: String| SpecError::Invalid {
+            name: name.to_string(),
+            reason,
+        };
+
+        if self.title.is_blank() {
+            return Err(invalid("`title` must not be empty".to_string()));
+        }
+        if self.text.is_blank() {
+            return Err(invalid("`text` must not be empty".to_string()));
+        }
+        if self.buttons.len() > 2 {
+            return Err(invalid(format!(
+                "at most 2 buttons are supported, found {}",
+                self.buttons.len()
+            )));
+        }
+
+        let buttons = self
+            .buttons
+            .iter()
+            .map(|label| ButtonLabel::from(label.as_str()).map_err(|err| invalid(err.to_string())))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if buttons.len() == 1 && self.default == DefaultButton::No {
+            return Err(invalid(
+                "`default = \"no\"` requires two buttons".to_string(),
+            ));
+        }
+
+        let mut state = ConfirmDialogState::new(
+            self.id.unwrap_or_else(|| stable_id(name)),
+            self.title.to_line(),
+            self.text.to_text(),
+        );
+
+        if let Some(severity) = self.severity {
+            state.with_severity(severity);
+        }
+        if let Some(details) = &self.details {
+            state.with_details(details.to_text());
+        }
+        let details_links = self.details.iter().flat_map(TextSpec::links);
+        for (text, url) in self.text.links().into_iter().chain(details_links) {
+            check_link(&text, &url).map_err(invalid)?;
+            state.with_hyperlink(text, url);
+        }
+
+        let mut buttons = buttons.into_iter();
+        if let Some(yes) = buttons.next() {
+            state
+                .with_buttons(yes, buttons.next())
+                .map_err(|err| invalid(err.to_string()))?;
+        }
+
+        state
+            .modal(self.modal)
+            .with_yes_button_selected(self.default == DefaultButton::Yes);
+        Ok(state)
+    }
+}
+
+impl PopupSpec {
+    /// Build the popup
+    pub fn to_popup(&self) -> PopupMessage<'static, 'static> {
+        let mut popup = PopupMessage::new(self.title.to_line(), self.text.to_text());
+        if let Some(severity) = self.severity {
+            popup = popup.severity(severity);
+        }
+        if let Some(alignment) = self.text_alignment {
+            popup = popup.text_alignment(alignment);
+        }
+        if let Some(alignment) = self.title_alignment {
+            popup = popup.title_alignment(alignment);
+        }
+        for (text, url) in self.text.links() {
+            popup = popup.hyperlink(text, url);
+        }
+        popup
+    }
+
+    fn validate(&self, name: &str) -> Result<(), SpecError> {
+        if self.text.is_blank() {
+            return Err(SpecError::Invalid {
+                name: name.to_string(),
+                reason: "`text` must not be empty".to_string(),
+            });
+        }
+        for (text, url) in self.text.links() {
+            check_link(&text, &url).map_err(|reason| SpecError::Invalid {
+                name: name.to_string(),
+                reason,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Reject a link with control characters, which could inject escape sequences
+fn check_link(text: &str, url: &str) -> Result<(), String> {
+    if url.chars().any(char::is_control) {
+        return Err(format!(
+            "the link of `{text}` must not contain control characters"
+        ));
+    }
+    Ok(())
+}
+
+/// FNV-1a hash of `name` folded to 16 bits
+fn stable_id(name: &str) -> u16 {
+    let hash = name.bytes().fold(0x811c9dc5u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x01000193)
+    });
+    ((hash >> 16) ^ (hash & 0xffff)) as u16
+}
+
+impl From<std::io::Error> for SpecError {
+    fn from(value: std::io::Error) -> Self {
+        SpecError::Io(value)
+    }
+}
+
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpecError::Io(err) => write!(f, "could not read dialog specs: {err}"),
+            SpecError::Toml(err) => write!(f, "invalid TOML dialog specs: {err}"),
+            #[cfg(feature = "yaml")]
+            SpecError::Yaml(err) => write!(f, "invalid YAML dialog specs: {err}"),
+            SpecError::UnsupportedFormat(path) => {
+                write!(f, "unsupported dialog specs format for {}", path.display())
+            }
+            SpecError::NotFound(name) => write!(f, "no dialog named `{name}`"),
+            SpecError::Invalid { name, reason } => write!(f, "invalid dialog `{name}`: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SpecError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SpecError::Io(err) => Some(err),
+            SpecError::Toml(err) => Some(err),
+            #[cfg(feature = "yaml")]
+            SpecError::Yaml(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,1311 @@
+mod snapshot;
+
+use crate::{ButtonLabel, ButtonLabelError};
+
+#[test]
+fn test_label() {
+    let label: ButtonLabel = "(Y)es".try_into().unwrap();
+    println!("label: {:?}", label);
+    assert_eq!('y', label.control);
+    assert_eq!("(Y)es", label.label);
+
+    let label: ButtonLabel = "Can(c)el".try_into().unwrap();
+    println!("label: {:?}", label);
+    assert_eq!('c', label.control);
+    assert_eq!("Can(c)el", label.label);
+
+    let label: ButtonLabel = "No".try_into().unwrap();
+    println!("label: {:?}", label);
+    assert_eq!('n', label.control);
+    assert_eq!("(N)o", label.label);
+
+    let label: ButtonLabel = "N".try_into().unwrap();
+    println!("label: {:?}", label);
+    assert_eq!('n', label.control);
+    assert_eq!("(N)", label.label);
+
+    let label: ButtonLabel = "S(ì)".try_into().unwrap();
+    println!("label: {:?}", label);
+    assert_eq!('ì', label.control);
+    assert_eq!("S(ì)", label.label);
+
+    let label: Result<ButtonLabel, ButtonLabelError> = "".try_into();
+    println!("label: {:?}", label);
+    label.expect_err("Expected and error");
+}
+
+#[test]
+fn test_label_from() {
+    let label: ButtonLabel = ButtonLabel::from("(Y)es").unwrap();
+    println!("label: {:?}", label);
+    assert_eq!('y', label.control);
+    assert_eq!("(Y)es", label.label);
+}
+
+#[test]
+fn test_label_errors() {
+    assert_eq!(Err(ButtonLabelError::Empty), ButtonLabel::from(""));
+    assert_eq!(
+        Err(ButtonLabelError::WhitespaceOnly),
+        ButtonLabel::from(" \t ")
+    );
+
+    let err = ButtonLabel::from("(Y)es (N)o").unwrap_err();
+    assert!(matches!(err, ButtonLabelError::MultipleMnemonics { .. }));
+    assert_eq!(
+        "button label `(Y)es (N)o` has more than one mnemonic marker, keep a single `(x)` or `&x`",
+        err.to_string()
+    );
+    assert!(matches!(
+        ButtonLabel::from("&Save &As"),
+        Err(ButtonLabelError::MultipleMnemonics { .. })
+    ));
+
+    let err = ButtonLabel::from("(?)Help").unwrap_err();
+    assert_eq!(
+        ButtonLabelError::InvalidMnemonic {
+            label: "(?)Help".to_string(),
+            mnemonic: "?".to_string()
+        },
+        err
+    );
+    assert_eq!(
+        "button label `(?)Help` has an invalid mnemonic `?`, use a letter or a digit",
+        err.to_string()
+    );
+    assert!(matches!(
+        ButtonLabel::from("&!Now"),
+        Err(ButtonLabelError::InvalidMnemonic { .. })
+    ));
+
+    // a lone `&` is kept, leading whitespace is skipped by the automatic mnemonic
+    assert_eq!(
+        "(F)ish & Chips",
+        ButtonLabel::from("Fish & Chips").unwrap().label
+    );
+    assert_eq!(" (O)k", ButtonLabel::from(" Ok").unwrap().label);
+}
+
+#[test]
+fn test_label_unicode() {
+    let label = ButtonLabel::from("S(ì)").unwrap();
+    assert_eq!('ì', label.control);
+    assert_eq!(6, label.len());
+
+    let label = ButtonLabel::from("Élan").unwrap();
+    assert_eq!('é', label.control);
+    assert_eq!("(É)lan", label.label);
+
+    // a base character followed by a combining accent is a single mnemonic
+    let label = ButtonLabel::from("e\u{301}cole").unwrap();
+    assert_eq!('e', label.control);
+    assert_eq!("(e\u{301})cole", label.label);
+    assert_eq!(9, label.len());
+
+    let label = ButtonLabel::from("(確)認").unwrap();
+    assert_eq!('確', label.control);
+    assert_eq!(8, label.len());
+
+    // the automatic mnemonic skips what cannot be typed as a key
+    let label = ButtonLabel::from("👍 OK").unwrap();
+    assert_eq!('o', label.control);
+    assert_eq!("👍 (O)K", label.label);
+    let label = ButtonLabel::from("?Help").unwrap();
+    assert_eq!('h', label.control);
+    assert_eq!("?(H)elp", label.label);
+    assert_eq!(
+        Err(ButtonLabelError::InvalidMnemonic {
+            label: " 👍".to_string(),
+            mnemonic: "👍".to_string()
+        }),
+        ButtonLabel::from(" 👍")
+    );
+    assert!(matches!(
+        ButtonLabel::from("(_)Next"),
+        Err(ButtonLabelError::InvalidMnemonic { .. })
+    ));
+}
+
+#[test]
+fn test_label_ampersand() {
+    let label = ButtonLabel::from("&Yes").unwrap();
+    assert_eq!('y', label.control);
+    assert_eq!("(Y)es", label.label);
+
+    let label = ButtonLabel::from("Save &As").unwrap();
+    assert_eq!('a', label.control);
+    assert_eq!("Save (A)s", label.label);
+
+    let label = ButtonLabel::from("Fish && &Chips").unwrap();
+    assert_eq!('c', label.control);
+    assert_eq!("Fish & (C)hips", label.label);
+
+    let label = ButtonLabel::from("R&&D").unwrap();
+    assert_eq!('r', label.control);
+    assert_eq!("(R)&D", label.label);
+
+    let label = ButtonLabel::from("&ìndietro").unwrap();
+    assert_eq!('ì', label.control);
+    assert_eq!(Some(("", "ì", "ndietro")), label.mnemonic_parts());
+}
+
+#[test]
+fn test_mnemonic_highlight() {
+    use ratatui_core::style::{Modifier, Style};
+
+    use crate::testing::{buffer_to_string, render_dialog_buffer};
+    use crate::{ConfirmDialog, ConfirmDialogState, MnemonicDisplay};
+
+    let mut state = ConfirmDialogState::new(1, "Title", "Delete the file?");
+    state
+        .with_yes_button(ButtonLabel::from("&Delete").unwrap())
+        .with_no_button(ButtonLabel::from("(K)eep").unwrap());
+    let dialog = ConfirmDialog::default()
+        .mnemonic_display(MnemonicDisplay::Highlight)
+        .mnemonic_style(Style::new().bold());
+    let buffer = render_dialog_buffer(dialog, &mut state, 50, 10);
+    let screen = buffer_to_string(&buffer);
+
+    let (y, line) = screen
+        .lines()
+        .enumerate()
+        .find(|(_, line)| line.contains("Delete  "))
+        .unwrap();
+    assert!(line.contains("Delete    Keep"));
+    let x = line.find('D').unwrap() as u16;
+    assert!(buffer[(x, y as u16)].modifier.contains(Modifier::BOLD));
+    assert!(!buffer[(x + 1, y as u16)].modifier.contains(Modifier::BOLD));
+}
+
+#[test]
+fn test_mnemonic_conflicts() {
+    use crate::ConfirmDialogState;
+
+    // automatic mnemonics move to a free letter, preferring the `no` button
+    let mut state = ConfirmDialogState::new(1, "Title", "Message");
+    state
+        .with_yes_button(ButtonLabel::from("Save").unwrap())
+        .with_no_button(ButtonLabel::from("Skip").unwrap());
+    assert_eq!("(S)ave", state.yes_button.label);
+    assert_eq!("S(k)ip", state.no_button.as_ref().unwrap().label);
+    assert_eq!('k', state.no_button.as_ref().unwrap().control);
+    assert!(state.mnemonic_conflict().is_none());
+
+    state
+        .with_yes_button(ButtonLabel::from("Keep").unwrap())
+        .with_no_button(ButtonLabel::from("(K)ill").unwrap());
+    assert_eq!("K(e)ep", state.yes_button.label);
+    assert_eq!('k', state.no_button.as_ref().unwrap().control);
+
+    // explicit mnemonics are kept and reported
+    let err = state
+        .with_buttons(
+            ButtonLabel::from("(D)elete").unwrap(),
+            Some(ButtonLabel::from("&Discard").unwrap()),
+        )
+        .unwrap_err();
+    assert_eq!('d', err.mnemonic);
+    assert_eq!(
+        "buttons `(D)elete` and `(D)iscard` share the mnemonic `d`",
+        err.to_string()
+    );
+    assert_eq!("K(e)ep", state.yes_button.label);
+
+    state
+        .with_yes_button(ButtonLabel::from("(D)elete").unwrap())
+        .with_no_button(ButtonLabel::from("&Discard").unwrap());
+    assert_eq!(Some('d'), state.mnemonic_conflict().map(|c| c.mnemonic));
+
+    // no free letter left
+    let mut state = ConfirmDialogState::new(1, "Title", "Message");
+    state
+        .with_yes_button(ButtonLabel::from("(A)").unwrap())
+        .with_no_button(ButtonLabel::from("A").unwrap());
+    assert!(state.mnemonic_conflict().is_some());
+    // a conflict with a replaced button does not stick
+    let mut state = ConfirmDialogState::new(1, "Title", "Message");
+    state
+        .with_yes_button(ButtonLabel::from("Nuke").unwrap())
+        .with_no_button(ButtonLabel::from("Keep").unwrap());
+    assert_eq!("(N)uke", state.yes_button.label);
+    assert_eq!('n', state.yes_button.control);
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn test_mnemonic_conflict_handle() {
+    use crate::testing::run_script;
+
+    let mut state = crate::ConfirmDialogState::new(1, "Title", "Message");
+    state
+        .with_yes_button(ButtonLabel::from("(D)elete").unwrap())
+        .with_no_button(ButtonLabel::from("(D)iscard").unwrap());
+    let run = run_script(&mut state, "Type \"d\"").unwrap();
+    assert_eq!(None, run.outcome);
+    assert!(run.messages.is_empty());
+}
+
+#[test]
+fn test_wide_buttons_layout() {
+    use crate::testing::render_dialog;
+    use crate::{ConfirmDialog, ConfirmDialogState};
+
+    let mut state = ConfirmDialogState::new(1, "確認", "ファイルを削除しますか？");
+    state
+        .with_yes_button(ButtonLabel::from("(確)認").unwrap())
+        .with_no_button(ButtonLabel::from("(取)消").unwrap());
+    let screen = render_dialog(ConfirmDialog::default(), &mut state, 50, 10);
+    assert!(screen.contains("(確)認    (取)消"));
+    assert!(screen.contains("ファイルを削除しますか？"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_theme_from_toml() {
+    use ratatui_core::style::{Color, Modifier};
+    use ratatui_widgets::borders::{BorderType, Borders};
+
+    let theme = crate::Theme::from_toml_str(
+        r##"
+        bg = "black"
+        borders = "ALL"
+        border_type = "Rounded"
+        padding = { left = 1, right = 1, top = 0, bottom = 0 }
+        title_alignment = "Left"
+        mnemonic_display = "highlight"
+        button_alignment = "space-between"
+        button_gap = 2
+
+        [selected_button_style]
+        fg = "#ffcc00"
+        modifiers = ["bold", "underlined"]
+        "##,
+    )
+    .unwrap();
+    assert_eq!(Color::Black, theme.bg);
+    assert_eq!(Borders::ALL, theme.borders);
+    assert_eq!(BorderType::Rounded, theme.border_type);
+    assert_eq!(1, theme.padding.left);
+    assert_eq!(crate::MnemonicDisplay::Highlight, theme.mnemonic_display);
+    assert_eq!(crate::ButtonAlignment::SpaceBetween, theme.button_alignment);
+    assert_eq!(2, theme.button_gap);
+    assert_eq!(
+        Some(Color::Rgb(0xff, 0xcc, 0x00)),
+        theme.selected_button_style.fg
+    );
+    assert!(theme
+        .selected_button_style
+        .add_modifier
+        .contains(Modifier::BOLD | Modifier::UNDERLINED));
+
+    let json = theme.to_json_string().unwrap();
+    assert_eq!(theme, crate::Theme::from_json_str(&json).unwrap());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_theme_errors() {
+    let err = crate::Theme::from_toml_str(r#"bg = "blurple""#).unwrap_err();
+    assert!(err.to_string().contains("invalid color `blurple`"));
+
+    let err =
+        crate::Theme::from_json_str(r#"{"text_style": {"modifiers": ["blinking"]}}"#).unwrap_err();
+    assert!(err.to_string().contains("invalid modifier `blinking`"));
+
+    let err =
+        crate::Theme::from_toml_str("padding = { left = 40000, right = 1, top = 0, bottom = 0 }")
+            .unwrap_err();
+    assert!(err.to_string().contains("padding 40000 is wider than 255"));
+
+    let err = crate::Theme::from_file("theme.yaml").unwrap_err();
+    assert!(matches!(err, crate::ThemeError::UnsupportedFormat(_)));
+}
+
+#[test]
+fn test_severity_buttons() {
+    let mut state = crate::ConfirmDialogState::new(1, "Title", "Message");
+    state.with_severity(crate::Severity::Error);
+    assert_eq!(Some(crate::Severity::Error), state.severity());
+    assert_eq!('o', state.yes_button.control);
+    assert!(state.no_button.is_none());
+
+    state.with_severity(crate::Severity::Question);
+    assert_eq!('y', state.yes_button.control);
+    assert_eq!('n', state.no_button.as_ref().unwrap().control);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_theme_severities() {
+    let theme = crate::Theme::from_toml_str(
+        r#"
+        [severities.error]
+        color = "light-red"
+        icon = "!"
+        yes_button = "(D)ismiss"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        ratatui_core::style::Color::LightRed,
+        theme.severities.error.color
+    );
+    assert_eq!("!", theme.severities.error.icon);
+    assert_eq!(crate::Severities::default().info, theme.severities.info);
+
+    let mut state = crate::ConfirmDialogState::new(1, "Title", "Message");
+    state.with_themed_severity(crate::Severity::Error, &theme);
+    assert_eq!('d', state.yes_button.control);
+
+    let err = crate::Theme::from_toml_str(
+        r#"
+        [severities.warning]
+        color = "yellow"
+        icon = "!"
+        yes_button = "(Y)es"
+        no_button = "(?)Help"
+        "#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("invalid mnemonic `?`"), "{err}");
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn test_dont_ask_again() {
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let store = crate::MemoryDecisionStore::new().shared();
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    let mut state = crate::ConfirmDialogState::new(7, "Title", "Message");
+    state
+        .with_listener(Some(tx))
+        .with_dont_ask_again(store.clone());
+
+    state.open();
+    assert!(state.is_opened());
+    assert!(state.handle(&KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE)));
+    assert!(state.is_dont_ask_again_checked());
+    assert!(state.handle(&KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE)));
+    assert_eq!((7, Some(false)), rx.try_recv().unwrap());
+    assert_eq!(Some(false), store.lock().unwrap().get(7));
+
+    state.open();
+    assert!(!state.is_opened());
+    assert_eq!((7, Some(false)), rx.try_recv().unwrap());
+    assert!(state.last_store_error().is_none());
+
+    #[derive(Debug)]
+    struct ReadOnlyStore;
+
+    impl crate::DecisionStore for ReadOnlyStore {
+        fn get(&self, _: u16) -> Option<bool> {
+            None
+        }
+
+        fn set(&mut self, _: u16, _: bool) -> std::io::Result<()> {
+            Err(std::io::ErrorKind::PermissionDenied.into())
+        }
+
+        fn remove(&mut self, _: u16) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut state = crate::ConfirmDialogState::new(8, "Title", "Message");
+    state.with_dont_ask_again(std::sync::Arc::new(std::sync::Mutex::new(ReadOnlyStore)));
+    state.open();
+    state.handle(&KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+    state.handle(&KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+    assert!(!state.is_opened());
+    assert_eq!(
+        Some(std::io::ErrorKind::PermissionDenied),
+        state.last_store_error().map(std::io::Error::kind)
+    );
+    state.open();
+    assert!(state.last_store_error().is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_json_file_decision_store() {
+    use crate::DecisionStore;
+
+    let path =
+        std::env::temp_dir().join(format!("tui-confirm-decisions-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut store = crate::JsonFileDecisionStore::open(&path).unwrap();
+    assert_eq!(None, store.get(1));
+    store.set(1, true).unwrap();
+    store.set(2, false).unwrap();
+
+    let mut store = crate::JsonFileDecisionStore::open(&path).unwrap();
+    assert_eq!(Some(true), store.get(1));
+    assert_eq!(Some(false), store.get(2));
+    store.remove(1).unwrap();
+
+    let store = crate::JsonFileDecisionStore::open(&path).unwrap();
+    assert_eq!(None, store.get(1));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_dialog_specs() {
+    let specs = crate::DialogSpecs::from_toml_str(
+        r#"
+        [dialogs.delete_files]
+        title = " Delete files "
+        text = [
+            "Are you sure you want to delete all files?",
+            [{ text = "This action cannot be undone.", style = { fg = "red", modifiers = ["bold"] } }],
+            [{ text = "See " }, { text = "the docs", link = "https://example.com/docs" }],
+        ]
+        buttons = ["(D)elete", "(K)eep"]
+        modal = true
+        default = "no"
+
+        [dialogs.saved]
+        id = 42
+        title = "Saved"
+        text = "The file was saved"
+        severity = "success"
+        details = "Written to /tmp/report.txt"
+
+        [popups.loading]
+        title = " Loading "
+        text = "Please wait..."
+        text_alignment = "Center"
+        "#,
+    )
+    .unwrap();
+
+    let state = specs.confirm_dialog("delete_files").unwrap();
+    assert_eq!(3, state.text.lines.len());
+    assert_eq!(
+        vec![(
+            "the docs".to_string(),
+            "https://example.com/docs".to_string()
+        )],
+        state.hyperlinks
+    );
+    assert_eq!('d', state.yes_button.control);
+    assert_eq!('k', state.no_button.as_ref().unwrap().control);
+    assert!(state.modal);
+    assert!(!state.yes_selected);
+    assert_eq!(state.id, specs.confirm_dialog("delete_files").unwrap().id);
+
+    let state = specs.confirm_dialog("saved").unwrap();
+    assert_eq!(42, state.id);
+    assert_eq!(Some(crate::Severity::Success), state.severity());
+    assert!(state.no_button.is_none());
+    assert_eq!(1, state.details.as_ref().unwrap().lines.len());
+
+    let popup = specs.popup_message("loading").unwrap();
+    assert_eq!(
+        ratatui_core::layout::Alignment::Center,
+        popup.text_alignment
+    );
+
+    assert!(matches!(
+        specs.confirm_dialog("missing"),
+        Err(crate::SpecError::NotFound(_))
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_dialog_specs_validation() {
+    let invalid = |buttons: &str| {
+        let spec =
+            format!("[dialogs.test]\ntitle = \"Title\"\ntext = \"Text\"\nbuttons = {buttons}\n");
+        let err = crate::DialogSpecs::from_toml_str(&spec).unwrap_err();
+        err.to_string()
+    };
+
+    assert!(invalid(r#"["(Y)es", "(Y)eah"]"#).contains("share the mnemonic `y`"));
+    assert!(invalid(r#"["(Y)es (N)o"]"#).contains("more than one mnemonic marker"));
+    assert!(invalid(r#"["(?)Help"]"#).contains("invalid mnemonic `?`"));
+    assert!(invalid(r#"["  "]"#).contains("must not be empty"));
+    assert!(invalid(r#"["A", "B", "C"]"#).contains("at most 2 buttons"));
+
+    let err = crate::DialogSpecs::from_toml_str("[dialogs.test]\ntitle = \"Title\"\n").unwrap_err();
+    assert!(err.to_string().contains("missing field `text`"));
+
+    // the id keys the remembered decisions, so it must be unique
+    let dialog =
+        |name: &str, id: &str| format!("[dialogs.{name}]\n{id}title = \"T\"\ntext = \"T\"\n");
+    let duplicate = |spec: String| {
+        crate::DialogSpecs::from_toml_str(&spec)
+            .unwrap_err()
+            .to_string()
+    };
+    assert!(
+        duplicate(dialog("a", "id = 3\n") + &dialog("b", "id = 3\n"))
+            .contains("id 3 is already used by dialog `a`")
+    );
+    assert!(
+        duplicate(dialog("dialog_68", "") + &dialog("dialog_332", ""))
+            .contains("set an explicit `id`")
+    );
+    assert!(crate::DialogSpecs::from_toml_str(
+        &(dialog("dialog_68", "") + &dialog("dialog_332", "id = 1\n"))
+    )
+    .is_ok());
+
+    // a link must not smuggle escape sequences into the OSC 8 hyperlink
+    for section in ["dialogs", "popups"] {
+        let spec = format!(
+            "[{section}.test]\ntitle = \"T\"\ntext = [[{{ text = \"docs\", link = \"https://x\\u001b]52;c;aGk=\\u0007\" }}]]\n"
+        );
+        let err = crate::DialogSpecs::from_toml_str(&spec).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("the link of `docs` must not contain control characters"));
+    }
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn test_dialog_specs_yaml() {
+    let specs = crate::DialogSpecs::from_yaml_str(
+        r#"
+dialogs:
+  quit:
+    title: " Quit "
+    text: "Quit the application?"
+    buttons: ["(Q)uit", "(C)ancel"]
+"#,
+    )
+    .unwrap();
+    let state = specs.confirm_dialog("quit").unwrap();
+    assert_eq!('q', state.yes_button.control);
+    assert_eq!('c', state.no_button.as_ref().unwrap().control);
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn test_dialog_outcome() {
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use crate::DialogOutcome;
+
+    let mut state = crate::ConfirmDialogState::new(1, "Title", "Message");
+    state.open();
+    assert_eq!(None, state.outcome());
+    state.handle(&KeyEvent::new(KeyCode::Right, KeyModifiers::NONE));
+    state.handle(&KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_eq!(Some(DialogOutcome::No), state.outcome());
+
+    state.open();
+    assert_eq!(None, state.outcome());
+    state.handle(&KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(Some(DialogOutcome::Cancelled), state.outcome());
+    assert_eq!(None, Option::<bool>::from(DialogOutcome::Cancelled));
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn test_key_script() {
+    use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+    use crate::testing::{parse_script, render_dialog, run_script};
+    use crate::{ConfirmDialog, DialogOutcome, MemoryDecisionStore};
+
+    let events = parse_script(
+        "# navigate\nSet FontSize 32\nRight 2 Left@100ms\nSleep 1s\nType \"ny\" Ctrl+C",
+    )
+    .unwrap();
+    let codes = events.iter().map(|event| event.code).collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            KeyCode::Right,
+            KeyCode::Right,
+            KeyCode::Left,
+            KeyCode::Char('n'),
+            KeyCode::Char('y'),
+            KeyCode::Char('c'),
+        ],
+        codes
+    );
+    assert_eq!(KeyModifiers::CONTROL, events[5].modifiers);
+
+    let err = parse_script("Enter\nJump").unwrap_err();
+    assert_eq!(2, err.line);
+    assert_eq!("line 2: unknown command `Jump`", err.to_string());
+    assert!(parse_script("Type \"oops").is_err());
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut state = crate::ConfirmDialogState::new(7, "Title", "Message");
+    state
+        .with_listener(Some(tx))
+        .with_dont_ask_again(MemoryDecisionStore::new().shared());
+
+    let run = run_script(&mut state, "Space Right Enter Enter").unwrap();
+    assert_eq!(Some(DialogOutcome::No), run.outcome);
+    assert_eq!(vec![(7, Some(false))], run.messages);
+    assert_eq!(3, run.keys);
+    assert_eq!(Ok((7, Some(false))), rx.try_recv());
+
+    // the decision is remembered, the dialog answers without handling any key
+    let run = run_script(&mut state, "Left Enter").unwrap();
+    assert_eq!(vec![(7, Some(false))], run.messages);
+    assert_eq!(0, run.keys);
+
+    let mut state = crate::ConfirmDialogState::new(8, "Title", "Message");
+    let run = run_script(&mut state, "Right").unwrap();
+    assert_eq!(None, run.outcome);
+    let screen = render_dialog(ConfirmDialog::default(), &mut state, 60, 12);
+    assert!(screen.contains("Message"));
+}
+
+#[test]
+fn test_translations() {
+    use crate::{ConfirmDialogState, Localization, Severities, Translations};
+
+    let german = Translations::for_locale("de_CH.UTF-8");
+    assert_eq!("(J)a", german.yes.label);
+    assert_eq!('j', german.yes.control);
+    assert_eq!("Nicht mehr fragen", german.dont_ask_again);
+
+    assert_eq!('s', Translations::for_locale("it").yes.control);
+    assert_eq!("(N)on", Translations::for_locale("fr-FR").no.label);
+    assert_eq!("(Y)es", Translations::for_locale("pt-BR").yes.label);
+
+    let mut localization = Localization::new();
+    localization.with_bundle(
+        "de-AT",
+        Translations::new("&Jo", "&Na", "&OK", "&Abbrechen", "Nimma fragn").unwrap(),
+    );
+    assert_eq!("(J)o", localization.get("de_AT").yes.label);
+    assert_eq!("(J)a", localization.get("de-DE").yes.label);
+
+    // new dialogs keep the plain English buttons whatever the environment
+    let state = ConfirmDialogState::new(1, "Title", "Message");
+    assert_eq!("Yes", state.yes_button.label);
+    assert_eq!("No", state.no_button.as_ref().unwrap().label);
+    assert_eq!(
+        Severities::localized(&Translations::english()),
+        Severities::default()
+    );
+
+    let mut state = ConfirmDialogState::new(1, "Titolo", "Messaggio");
+    state
+        .with_dont_ask_again(crate::MemoryDecisionStore::new().shared())
+        .with_translations(&Translations::for_locale("it"));
+    assert_eq!("(S)ì", state.yes_button.label);
+    assert_eq!("(N)o", state.no_button.as_ref().unwrap().label);
+    assert_eq!(Some("Non chiedere più"), state.dont_ask_again.as_deref());
+
+    // the checkbox is translated whichever is set first
+    let mut state = ConfirmDialogState::new(1, "Titolo", "Messaggio");
+    state
+        .with_translations(&Translations::for_locale("it"))
+        .with_dont_ask_again(crate::MemoryDecisionStore::new().shared());
+    assert_eq!(Some("Non chiedere più"), state.dont_ask_again.as_deref());
+
+    let severities = Severities::localized(&german);
+    assert_eq!("(O)K", severities.error.yes_button);
+    assert_eq!(Some("(A)bbrechen"), severities.warning.no_button.as_deref());
+}
+
+#[cfg(feature = "fluent")]
+#[test]
+fn test_fluent_translations() {
+    use crate::{Localization, LocalizationError};
+
+    let mut localization = Localization::new();
+    localization
+        .with_fluent(
+            "fr-CA",
+            "confirm-yes = &Oui\nconfirm-cancel = Annu(l)er\nconfirm-dont-ask-again = Ne plus me demander\n",
+        )
+        .unwrap();
+    let translations = localization.get("fr-CA");
+    assert_eq!("(O)ui", translations.yes.label);
+    assert_eq!('l', translations.cancel.control);
+    assert_eq!("(N)on", translations.no.label);
+    assert_eq!("Ne plus me demander", translations.dont_ask_again);
+
+    let err = localization
+        .with_fluent("de", "confirm-yes = (J)a (N)ein\n")
+        .unwrap_err();
+    assert!(matches!(err, LocalizationError::Label { ref id, .. } if id == "confirm-yes"));
+    assert!(localization.with_fluent("de", "confirm-yes = {").is_err());
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn test_button_roles() {
+    use ratatui_core::style::Color;
+
+    use crate::testing::{render_dialog, render_dialog_buffer, run_script};
+    use crate::{ButtonOrder, ButtonRole, ConfirmDialog, ConfirmDialogState, DialogOutcome};
+
+    let state = || {
+        let mut state = ConfirmDialogState::new(1, "Title", "Discard the changes?");
+        state
+            .with_yes_button(
+                ButtonLabel::from("(D)iscard")
+                    .unwrap()
+                    .with_role(ButtonRole::Destructive),
+            )
+            .with_no_button(
+                ButtonLabel::from("(C)ancel")
+                    .unwrap()
+                    .with_role(ButtonRole::Cancel)
+                    .with_default(true),
+            );
+        state
+    };
+
+    // Esc presses the cancel button, Enter the default one
+    let mut dialog = state();
+    assert_eq!(
+        Some(DialogOutcome::No),
+        run_script(&mut dialog, "Escape").unwrap().outcome
+    );
+    let mut dialog = state();
+    assert_eq!(
+        Some(DialogOutcome::No),
+        run_script(&mut dialog, "Enter").unwrap().outcome
+    );
+
+    // cancelling with "Don't ask again" ticked does not remember the answer
+    let store = crate::MemoryDecisionStore::new().shared();
+    let mut dialog = state();
+    dialog.with_dont_ask_again(store.clone());
+    let run = run_script(&mut dialog, "Space Escape").unwrap();
+    assert_eq!(Some(DialogOutcome::No), run.outcome);
+    assert_eq!(None, store.lock().unwrap().get(1));
+    dialog.open();
+    assert!(dialog.is_opened());
+
+    // affirmative last: the buttons and the arrows are swapped
+    let mut dialog = state();
+    dialog.open();
+    let screen = render_dialog(
+        ConfirmDialog::default().button_order(ButtonOrder::AffirmativeLast),
+        &mut dialog,
+        60,
+        12,
+    );
+    assert!(screen.contains("(C)ancel    (D)iscard"));
+    let run = run_script(&mut dialog, "Right Enter").unwrap();
+    assert_eq!(Some(DialogOutcome::Yes), run.outcome);
+
+    let mut dialog = state();
+    dialog.open();
+    let buffer = render_dialog_buffer(ConfirmDialog::default(), &mut dialog, 60, 12);
+    let screen = crate::testing::buffer_to_string(&buffer);
+    assert!(screen.contains("(D)iscard    (C)ancel"));
+    let (y, line) = screen
+        .lines()
+        .enumerate()
+        .find(|(_, line)| line.contains("(D)iscard"))
+        .unwrap();
+    let x = line.find("(D)").unwrap() as u16;
+    assert_eq!(Color::Red, buffer[(x, y as u16)].fg);
+    let run = run_script(&mut dialog, "Left Enter").unwrap();
+    assert_eq!(Some(DialogOutcome::Yes), run.outcome);
+
+    // without a cancel role Esc still cancels
+    let mut dialog = ConfirmDialogState::new(1, "Title", "Message");
+    assert_eq!(
+        Some(DialogOutcome::Cancelled),
+        run_script(&mut dialog, "Escape").unwrap().outcome
+    );
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn test_disabled_buttons() {
+    use crate::testing::{render_dialog, run_script};
+    use crate::{ConfirmDialog, ConfirmDialogState, DialogOutcome};
+
+    let state = || {
+        let mut state = ConfirmDialogState::new(1, "Title", "Publish the release?");
+        state
+            .with_yes_button(
+                ButtonLabel::from("(P)ublish")
+                    .unwrap()
+                    .with_disabled_reason("Tests are still running"),
+            )
+            .with_no_button(ButtonLabel::from("(C)ancel").unwrap());
+        state
+    };
+
+    // a disabled button is never selected nor pressed
+    let mut dialog = state();
+    let run = run_script(&mut dialog, "Left Enter").unwrap();
+    assert_eq!(Some(DialogOutcome::No), run.outcome);
+
+    let mut dialog = state();
+    let run = run_script(&mut dialog, "Type \"p\"").unwrap();
+    assert_eq!(None, run.outcome);
+    assert!(dialog.is_opened());
+
+    // the reason shows once the button is pressed, and goes away on the next key
+    let mut dialog = state();
+    dialog.with_yes_button_selected(false);
+    dialog.open();
+    let screen = render_dialog(ConfirmDialog::default(), &mut dialog, 60, 12);
+    assert!(screen.contains("(P)ublish"));
+    assert!(!screen.contains("Tests are still running"));
+    run_script(&mut dialog, "Type \"p\"").unwrap();
+    let screen = render_dialog(ConfirmDialog::default(), &mut dialog, 60, 12);
+    assert!(screen.contains("Tests are still running"));
+    run_script(&mut dialog, "Right").unwrap();
+    let screen = render_dialog(ConfirmDialog::default(), &mut dialog, 60, 12);
+    assert!(!screen.contains("Tests are still running"));
+
+    // enabled again
+    let mut dialog = state();
+    dialog.with_yes_disabled(false);
+    let run = run_script(&mut dialog, "Type \"p\"").unwrap();
+    assert_eq!(Some(DialogOutcome::Yes), run.outcome);
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn test_enable_delay() {
+    use std::time::{Duration, Instant};
+
+    use crate::testing::{render_dialog, run_script};
+    use crate::{ButtonRole, ConfirmDialog, ConfirmDialogState, DialogOutcome};
+
+    let mut state = ConfirmDialogState::new(1, "Title", "Delete all files?");
+    state
+        .with_yes_button(ButtonLabel::from("(D)elete").unwrap())
+        .with_yes_role(ButtonRole::Destructive)
+        .with_enable_delay(Duration::from_secs(3))
+        .with_input_guard(Duration::from_millis(300));
+    assert_eq!(None, state.remaining_delay());
+
+    // the countdown starts on open and moves the selection away from the affirmative button
+    state.open();
+    assert!(state.next_tick().is_some());
+    let screen = render_dialog(ConfirmDialog::default(), &mut state, 60, 12);
+    assert!(screen.contains("(D)elete (3)"));
+
+    // keys right after opening are ignored, then the delayed button can't be pressed
+    assert_eq!(None, run_script(&mut state, "Enter").unwrap().outcome);
+    state.opened_at = Some(Instant::now() - Duration::from_millis(1500));
+    assert_eq!(
+        None,
+        run_script(&mut state, "Left Type \"d\"").unwrap().outcome
+    );
+    let screen = render_dialog(ConfirmDialog::default(), &mut state, 60, 12);
+    assert!(screen.contains("(D)elete (2)"));
+
+    state.opened_at = Some(Instant::now() - Duration::from_secs(3));
+    assert_eq!(None, state.next_tick());
+    let screen = render_dialog(ConfirmDialog::default(), &mut state, 60, 12);
+    assert!(screen.contains("(D)elete "));
+    assert!(!screen.contains("(D)elete ("));
+    let run = run_script(&mut state, "Left Enter").unwrap();
+    assert_eq!(Some(DialogOutcome::Yes), run.outcome);
+}
+
+#[test]
+fn test_button_layout() {
+    use crate::testing::render_dialog;
+    use crate::{ButtonAlignment, ConfirmDialog, ConfirmDialogState};
+
+    let render = |dialog: ConfirmDialog| {
+        let mut state = ConfirmDialogState::new(1, "Title", "Remove the branch?");
+        state
+            .with_yes_button(ButtonLabel::from("(R)emove").unwrap())
+            .with_no_button(ButtonLabel::from("(K)eep").unwrap());
+        let screen = render_dialog(dialog, &mut state, 40, 12);
+        screen
+            .lines()
+            .filter(|line| line.contains("(R)emove") || line.contains("(K)eep"))
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    let dialog = || ConfirmDialog::default().borders(ratatui_widgets::borders::Borders::ALL);
+    assert_eq!(
+        vec!["│ (R)emove    (K)eep                   │"],
+        render(dialog().button_alignment(ButtonAlignment::Left))
+    );
+    assert_eq!(
+        vec!["│                   (R)emove    (K)eep │"],
+        render(dialog().button_alignment(ButtonAlignment::Right))
+    );
+    assert_eq!(
+        vec!["│ (R)emove                      (K)eep │"],
+        render(dialog().button_alignment(ButtonAlignment::SpaceBetween))
+    );
+    assert_eq!(
+        vec!["│ (R)emove (K)eep                      │"],
+        render(
+            dialog()
+                .button_alignment(ButtonAlignment::Left)
+                .button_gap(1)
+        )
+    );
+    assert_eq!(
+        vec!["│ (R)emove     (K)eep                  │"],
+        render(
+            dialog()
+                .button_alignment(ButtonAlignment::Left)
+                .equal_button_width(true)
+        )
+    );
+    assert_eq!(
+        vec![
+            "│               (R)emove               │",
+            "│                (K)eep                │",
+        ],
+        render(dialog().stacked_buttons(true))
+    );
+}
+
+#[test]
+fn test_key_hints() {
+    use crate::testing::render_dialog;
+    use crate::{ConfirmDialog, ConfirmDialogState, KeyHint};
+
+    let mut state = ConfirmDialogState::new(1, "Title", "Delete all files?");
+    let keys = |state: &ConfirmDialogState| {
+        state
+            .key_hints()
+            .into_iter()
+            .map(|hint| hint.key)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(vec!["←/→", "Enter", "Esc"], keys(&state));
+    assert_eq!(KeyHint::new("Esc", "cancel"), state.key_hints()[2]);
+
+    state.with_copy_key(Some(crate::Key::F(5)));
+    assert_eq!(vec!["←/→", "Enter", "Esc", "F5"], keys(&state));
+    state.with_copy_key(None);
+
+    state
+        .with_dont_ask_again(crate::MemoryDecisionStore::new().shared())
+        .modal(true);
+    assert_eq!(vec!["←/→", "Space", "Enter"], keys(&state));
+
+    let mut state = ConfirmDialogState::new(1, "Title", "Delete all files?");
+    state.without_no_button();
+    assert_eq!(vec!["Enter", "Esc"], keys(&state));
+
+    // the arrows only show up when the other button can be selected
+    let mut state = ConfirmDialogState::new(1, "Title", "Delete all files?");
+    state
+        .with_yes_disabled(true)
+        .with_yes_button_selected(false);
+    assert_eq!(vec!["Enter", "Esc"], keys(&state));
+    let mut state = ConfirmDialogState::new(1, "Title", "Delete all files?");
+    state
+        .with_enable_delay(std::time::Duration::from_secs(60))
+        .with_yes_button_selected(false)
+        .open();
+    assert_eq!(vec!["Enter", "Esc"], keys(&state));
+
+    let mut state = ConfirmDialogState::new(1, "Title", "Delete all files?");
+    state
+        .with_details("rm -rf ./build")
+        .with_details_expanded(true);
+    assert_eq!(
+        vec!["←/→", "Tab", "↑/↓", "PgUp/PgDn", "Enter", "Esc"],
+        keys(&state)
+    );
+
+    let mut state = ConfirmDialogState::new(1, "Title", "Delete all files?");
+    state.open();
+    let screen = render_dialog(ConfirmDialog::default().key_hints(true), &mut state, 60, 14);
+    let lines = screen.lines().collect::<Vec<_>>();
+    let buttons = lines.iter().position(|line| line.contains("Yes")).unwrap();
+    assert!(lines[buttons + 1].contains("←/→ select · Enter confirm · Esc cancel"));
+
+    // without enough room the footer goes away before the buttons are stacked
+    let screen = render_dialog(ConfirmDialog::default().key_hints(true), &mut state, 60, 2);
+    assert!(screen.contains("Yes"));
+    assert!(!screen.contains("Enter confirm"));
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn test_details() {
+    use crate::testing::{render_dialog, run_script};
+    use crate::{ConfirmDialog, ConfirmDialogState};
+
+    let mut state = ConfirmDialogState::new(1, "Title", "The upload failed.");
+    state.with_details(
+        (1..=20)
+            .map(|line| format!("trace line {line}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+    state.open();
+
+    // hidden by default
+    let dialog = || ConfirmDialog::default().details_height(4);
+    let collapsed = render_dialog(dialog(), &mut state, 60, 24);
+    assert!(collapsed.contains("▸ Details (Tab)"));
+    assert!(!collapsed.contains("trace line 1"));
+
+    // Tab expands them and the dialog grows
+    run_script(&mut state, "Tab").unwrap();
+    assert!(state.is_details_expanded());
+    let expanded = render_dialog(dialog(), &mut state, 60, 24);
+    assert!(expanded.contains("▾ Details (Tab)"));
+    assert!(expanded.contains("trace line 1"));
+    assert!(expanded.contains("trace line 4"));
+    assert!(!expanded.contains("trace line 5"));
+    let height = |screen: &str| screen.lines().filter(|line| !line.is_empty()).count();
+    assert!(height(&expanded) > height(&collapsed));
+
+    // the arrows scroll without going past the end
+    run_script(&mut state, "Down 2").unwrap();
+    let screen = render_dialog(dialog(), &mut state, 60, 24);
+    assert!(screen.contains("trace line 3"));
+    assert!(!screen.contains("trace line 2\n"));
+    run_script(&mut state, "PageDown 10").unwrap();
+    assert_eq!(16, state.details_scroll());
+    let screen = render_dialog(dialog(), &mut state, 60, 24);
+    assert!(screen.contains("trace line 20"));
+
+    run_script(&mut state, "Tab").unwrap();
+    assert!(!state.is_details_expanded());
+    assert!(state.is_opened());
+}
+
+#[test]
+fn test_from_error() {
+    use std::fmt;
+
+    use ratatui_core::style::Modifier;
+
+    use crate::testing::render_popup;
+    use crate::{ConfirmDialogState, PopupMessage, Severity};
+
+    #[derive(Debug)]
+    struct Failure(&'static str, Option<Box<Failure>>);
+
+    impl fmt::Display for Failure {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl std::error::Error for Failure {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            self.1.as_deref().map(|err| err as _)
+        }
+    }
+
+    let error = Failure(
+        "could not save the report",
+        Some(Box::new(Failure(
+            "could not open /tmp/report.txt",
+            Some(Box::new(Failure("permission denied", None))),
+        ))),
+    );
+
+    let screen = render_popup(PopupMessage::from_error(" Error ", &error), 60, 14);
+    assert!(screen.contains("could not save the report"));
+    assert!(screen.contains("Caused by:"));
+    assert!(screen.contains("  • could not open /tmp/report.txt"));
+    assert!(screen.contains("    • permission denied"));
+
+    let state = ConfirmDialogState::from_error(1, " Error ", &error);
+    assert_eq!(Some(Severity::Error), state.severity());
+    assert!(state.text.lines[0]
+        .style
+        .add_modifier
+        .contains(Modifier::BOLD));
+    assert_eq!(5, state.text.lines.len());
+
+    let state = ConfirmDialogState::from_error(1, " Error ", &Failure("timeout", None));
+    assert_eq!(1, state.text.lines.len());
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn test_clipboard() {
+    use crate::clipboard::{copy, osc52};
+    use crate::testing::{render_dialog, render_popup, run_script};
+    use crate::{ConfirmDialog, ConfirmDialogState, PopupMessage};
+
+    assert_eq!("\x1b]52;c;\x07", osc52(""));
+    assert_eq!("\x1b]52;c;YQ==\x07", osc52("a"));
+    assert_eq!("\x1b]52;c;YWI=\x07", osc52("ab"));
+    assert_eq!("\x1b]52;c;YWJj\x07", osc52("abc"));
+    assert_eq!("\x1b]52;c;w6ljaGVj\x07", osc52("échec"));
+    let mut output = Vec::new();
+    copy(&mut output, "abc").unwrap();
+    assert_eq!(osc52("abc").as_bytes(), output);
+
+    let popup = PopupMessage::new(" Token ", "Your token:\n  abc123");
+    assert_eq!("Token\n\nYour token:\n  abc123", popup.plain_text());
+    let screen = render_popup(popup.copied(true), 60, 14);
+    assert!(screen.contains("✓ Copied to clipboard"));
+
+    // copying is opt-in
+    let mut state = ConfirmDialogState::new(1, " Title ", "Delete all files?");
+    state
+        .with_no_button(ButtonLabel::from("(C)ancel").unwrap())
+        .with_details("rm -rf ./build");
+    let run = run_script(&mut state, "Ctrl+C").unwrap();
+    assert_eq!(None, state.take_copy_request());
+    // nor does Ctrl+C press the `(C)ancel` button
+    assert_eq!(None, run.outcome);
+    assert!(state.is_opened());
+
+    // the copy key copies instead of pressing the `(C)ancel` button
+    state.with_copy_key(Some(crate::clipboard::COPY_KEY));
+    let run = run_script(&mut state, "Ctrl+C").unwrap();
+    assert_eq!(None, run.outcome);
+    // the confirmation waits for the event loop to report the copy
+    assert!(!state.is_copied());
+    assert_eq!(
+        Some("Title\n\nDelete all files?\n\nrm -rf ./build".to_string()),
+        state.take_copy_request()
+    );
+    assert_eq!(None, state.take_copy_request());
+    let screen = render_dialog(ConfirmDialog::default(), &mut state, 60, 14);
+    assert!(!screen.contains("✓ Copied to clipboard"));
+    state.with_copied(true);
+    let screen = render_dialog(ConfirmDialog::default(), &mut state, 60, 14);
+    assert!(screen.contains("✓ Copied to clipboard"));
+
+    run_script(&mut state, "Left").unwrap();
+    assert!(!state.is_copied());
+}
+
+#[test]
+fn test_hyperlinks() {
+    use ratatui_core::buffer::{Buffer, CellDiffOption};
+    use ratatui_core::layout::Rect;
+    use ratatui_core::widgets::Widget;
+
+    use crate::testing::{buffer_to_string, render_dialog, render_dialog_buffer};
+    use crate::{ConfirmDialog, ConfirmDialogState, PopupMessage};
+
+    let url = "https://example.com/docs";
+    let link_cells = |buffer: &Buffer| {
+        buffer
+            .content
+            .iter()
+            .filter(|cell| cell.symbol().starts_with("\x1b]8;;"))
+            .map(|cell| {
+                assert_eq!(
+                    format!("\x1b]8;;{url}\x1b\\"),
+                    cell.symbol().split_inclusive('\\').next().unwrap()
+                );
+                assert!(matches!(cell.diff_option, CellDiffOption::ForcedWidth(_)));
+                crate::hyperlink::strip_hyperlink(cell.symbol()).into_owned()
+            })
+            .collect::<String>()
+    };
+
+    let mut state = ConfirmDialogState::new(1, " Title ", "Read the docs first");
+    state
+        .with_hyperlink("docs", url)
+        .with_hyperlink("the docs", url);
+    let buffer = render_dialog_buffer(
+        ConfirmDialog::default().hyperlinks(true),
+        &mut state,
+        60,
+        14,
+    );
+    assert_eq!("the docs", link_cells(&buffer));
+    assert_eq!(
+        render_dialog(
+            ConfirmDialog::default().hyperlinks(false),
+            &mut state,
+            60,
+            14
+        ),
+        buffer_to_string(&buffer)
+    );
+
+    let buffer = render_dialog_buffer(
+        ConfirmDialog::default().hyperlinks(false),
+        &mut state,
+        60,
+        14,
+    );
+    assert_eq!("", link_cells(&buffer));
+
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 60, 14));
+    PopupMessage::new(" Title ", "See the docs")
+        .hyperlink("docs", url)
+        .hyperlinks(true)
+        .render(buffer.area, &mut buffer);
+    assert_eq!("docs", link_cells(&buffer));
+
+    // control characters would end the sequence early
+    let mut state = ConfirmDialogState::new(1, " Title ", "Read the docs first");
+    state.with_hyperlink("docs", "https://example.com/\x1b]52;c;aGk=\x07docs");
+    assert_eq!(
+        vec![(
+            "docs".to_string(),
+            "https://example.com/]52;c;aGk=docs".to_string()
+        )],
+        state.hyperlinks
+    );
+    let popup = PopupMessage::new(" Title ", "See the docs").hyperlink("docs", "https://\x1b\\x");
+    assert_eq!(
+        vec![("docs".to_string(), "https://\\x".to_string())],
+        popup.links
+    );
+}
+
+#[cfg(feature = "markdown")]
+#[test]
+fn test_markdown() {
+    use ratatui_core::style::{Modifier, Style};
+
+    use crate::markdown::to_text;
+    use crate::testing::render_popup;
+    use crate::PopupMessage;
+
+    let text = to_text(
+        "# Cleanup\n\nThis will *permanently*\ndelete:\n\n- the **build** directory\n- `target`\n  - nested\n\n1. first\n2. second\n\n```\nrm -rf build\n\nrm -rf target\n```",
+    );
+    let plain = text
+        .lines
+        .iter()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            "Cleanup",
+            "",
+            "This will permanently delete:",
+            "",
+            "• the build directory",
+            "• target",
+            "  • nested",
+            "",
+            "1. first",
+            "2. second",
+            "",
+            "rm -rf build",
+            "",
+            "rm -rf target",
+        ],
+        plain
+    );
+
+    assert!(text.lines[0].spans[0]
+        .style
+        .add_modifier
+        .contains(Modifier::BOLD));
+    assert!(text.lines[2].spans[1]
+        .style
+        .add_modifier
+        .contains(Modifier::ITALIC));
+    assert_eq!(Style::new().bold(), text.lines[4].spans[2].style);
+    assert_ne!(Style::new(), text.lines[5].spans[1].style);
+
+    let screen = render_popup(PopupMessage::new(" Cleanup ", text), 60, 20);
+    assert!(screen.contains("• the build directory"));
+}
@@ -0,0 +1,242 @@
+//! Rendering snapshots of [`ConfirmDialog`] and [`PopupMessage`].
+//!
+//! Every snapshot is the text content of the rendered buffer, stored in
+//! `src/test/snapshots/<name>.txt`. To create new snapshots or accept intentional rendering
+//! changes run the tests with `UPDATE_SNAPSHOTS=1`, a missing snapshot fails otherwise:
+//!
+//! ```sh
+//! UPDATE_SNAPSHOTS=1 cargo test --all-features snapshot
+//! ```
+use std::path::PathBuf;
+
+use ratatui_core::style::{Color, Style};
+use ratatui_core::text::{Line, Text};
+use ratatui_widgets::block::Padding;
+use ratatui_widgets::borders::{BorderType, Borders};
+
+use crate::testing::{render_dialog, render_popup};
+use crate::{
+    ButtonAlignment, ButtonLabel, ConfirmDialog, ConfirmDialogState, PopupMessage, Severity, Theme,
+};
+
+/// Compare `actual` with the stored snapshot `name`, writing it instead when `UPDATE_SNAPSHOTS`
+/// is set
+pub(crate) fn assert_snapshot(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/test/snapshots")
+        .join(format!("{name}.txt"));
+
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some_and(|value| value != "0");
+    if update {
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    assert!(
+        path.exists(),
+        "snapshot `{name}` is missing, run with UPDATE_SNAPSHOTS=1 to create {}",
+        path.display()
+    );
+
+    let expected = std::fs::read_to_string(&path).unwrap();
+    assert!(
+        expected == actual,
+        "snapshot `{name}` does not match, run with UPDATE_SNAPSHOTS=1 to accept the changes\n\
+         --- expected ({})\n{expected}\n--- actual\n{actual}",
+        path.display()
+    );
+}
+
+const SIZES: [(u16, u16); 3] = [(40, 10), (60, 14), (80, 24)];
+
+fn dialog_state() -> ConfirmDialogState {
+    let mut state = ConfirmDialogState::new(
+        1,
+        " Please Select ",
+        Text::from(vec![
+            Line::from("Are you sure you want to delete all files?"),
+            Line::from("This action cannot be undone."),
+        ]),
+    );
+    state
+        .with_yes_button(ButtonLabel::from("(Y)es").unwrap())
+        .with_no_button(ButtonLabel::from("(N)o").unwrap());
+    state
+}
+
+fn rounded_theme() -> Theme {
+    Theme {
+        bg: Color::Black,
+        borders: Borders::ALL,
+        border_type: BorderType::Rounded,
+        selected_button_style: Style::new().bold(),
+        padding: Padding::new(1, 1, 1, 0),
+        ..Theme::default()
+    }
+}
+
+#[test]
+fn snapshot_confirm_dialog() {
+    for (width, height) in SIZES {
+        let dialog = ConfirmDialog::default().borders(Borders::ALL);
+        let output = render_dialog(dialog, &mut dialog_state(), width, height);
+        assert_snapshot(&format!("confirm_{width}x{height}"), &output);
+    }
+}
+
+#[test]
+fn snapshot_confirm_dialog_themes() {
+    let themes = [
+        ("default", Theme::default()),
+        ("rounded", rounded_theme()),
+        (
+            "double",
+            Theme {
+                borders: Borders::ALL,
+                border_type: BorderType::Double,
+                padding: Padding::uniform(3),
+                ..Theme::default()
+            },
+        ),
+    ];
+
+    for (name, theme) in themes {
+        let output = render_dialog(ConfirmDialog::from(&theme), &mut dialog_state(), 80, 24);
+        assert_snapshot(&format!("confirm_theme_{name}"), &output);
+    }
+}
+
+#[test]
+fn snapshot_confirm_dialog_buttons() {
+    let dialog = || ConfirmDialog::from(&rounded_theme());
+
+    let mut state = dialog_state();
+    state.without_no_button();
+    assert_snapshot(
+        "confirm_single_button",
+        &render_dialog(dialog(), &mut state, 60, 14),
+    );
+
+    let mut state = dialog_state();
+    state
+        .with_yes_button(ButtonLabel::from("(D)elete everything").unwrap())
+        .with_no_button(ButtonLabel::from("(K)eep").unwrap())
+        .with_yes_button_selected(false);
+    assert_snapshot(
+        "confirm_custom_buttons",
+        &render_dialog(dialog(), &mut state, 60, 14),
+    );
+
+    let mut state = dialog_state();
+    state.with_dont_ask_again(crate::MemoryDecisionStore::new().shared());
+    assert_snapshot(
+        "confirm_dont_ask_again",
+        &render_dialog(dialog(), &mut state, 60, 14),
+    );
+
+    for severity in [Severity::Warning, Severity::Error] {
+        let mut state = dialog_state();
+        state.with_severity(severity);
+        assert_snapshot(
+            &format!("confirm_severity_{severity:?}").to_lowercase(),
+            &render_dialog(dialog(), &mut state, 60, 14),
+        );
+    }
+}
+
+#[test]
+fn snapshot_popup_message() {
+    let popup = || {
+        PopupMessage::new(
+            " Loading ",
+            vec![
+                Line::from("Example popup showing a loading message"),
+                Line::from("The operation was successful"),
+            ],
+        )
+        .borders(Borders::ALL)
+    };
+
+    for (width, height) in SIZES {
+        assert_snapshot(
+            &format!("popup_{width}x{height}"),
+            &render_popup(popup(), width, height),
+        );
+    }
+
+    let output = render_popup(
+        popup().theme(&rounded_theme()).severity(Severity::Success),
+        60,
+        14,
+    );
+    assert_snapshot("popup_theme_rounded_success", &output);
+}
+
+#[test]
+fn snapshot_small_areas() {
+    let dialog = || ConfirmDialog::default().borders(Borders::ALL);
+
+    // padding shrinks, then buttons stack, then the dialog becomes a one-line prompt
+    for (width, height) in [(30, 8), (16, 9), (14, 5), (40, 3), (12, 1)] {
+        let output = render_dialog(dialog(), &mut dialog_state(), width, height);
+        assert_snapshot(&format!("confirm_small_{width}x{height}"), &output);
+    }
+
+    let mut state = dialog_state();
+    state
+        .with_yes_button_selected(false)
+        .with_title(" A very long title that cannot fit ");
+    assert_snapshot(
+        "confirm_small_title",
+        &render_dialog(dialog(), &mut state, 24, 8),
+    );
+
+    let popup =
+        PopupMessage::new(" A very long loading title ", "Loading files").borders(Borders::ALL);
+    assert_snapshot("popup_small_20x5", &render_popup(popup, 20, 5));
+}
+
+#[test]
+fn render_any_size() {
+    let themes = [Theme::default(), rounded_theme()];
+    for width in (0..=16).chain([24, 32, 45]) {
+        for height in 0..=10 {
+            for theme in &themes {
+                let mut state = dialog_state();
+                state.with_dont_ask_again(crate::MemoryDecisionStore::new().shared());
+                render_dialog(ConfirmDialog::from(theme), &mut state, width, height);
+
+                let mut state = dialog_state();
+                state.without_no_button().with_severity(Severity::Error);
+                render_dialog(ConfirmDialog::from(theme), &mut state, width, height);
+
+                // a gap from a theme file can be as large as it likes
+                for alignment in [ButtonAlignment::Center, ButtonAlignment::SpaceBetween] {
+                    let dialog = ConfirmDialog::from(theme)
+                        .button_gap(u16::MAX)
+                        .button_alignment(alignment);
+                    render_dialog(dialog.clone(), &mut dialog_state(), width, height);
+                    render_dialog(
+                        dialog.stacked_buttons(true),
+                        &mut dialog_state(),
+                        width,
+                        height,
+                    );
+                }
+
+                // so can the padding of one built in code
+                let padding = Padding::uniform(u16::MAX);
+                let dialog = ConfirmDialog::from(theme).padding(padding);
+                render_dialog(dialog, &mut dialog_state(), width, height);
+                let popup = PopupMessage::new("Title", "Message").theme(theme);
+                render_popup(popup.padding(padding), width, height);
+
+                render_popup(PopupMessage::new("", "").theme(theme), width, height);
+                render_popup(
+                    PopupMessage::new("Title", "Some message\nOn two lines").theme(theme),
+                    width,
+                    height,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! Helpers to test dialog flows without a terminal.
+//!
+//! A key script uses the syntax of the [VHS](https://github.com/charmbracelet/vhs) `.tape` files
+//! found in `examples/`: commands are separated by whitespace or newlines, key commands take an
+//! optional repeat count and `Type` sends every character of a quoted string.
+//!
+//! ```
+//! use std::sync::mpsc::channel;
+//!
+//! use tui_confirm_dialog::testing::{render_dialog, run_script};
+//! use tui_confirm_dialog::{ConfirmDialog, ConfirmDialogState, DialogOutcome};
+//!
+//! let (tx, rx) = channel();
+//! let mut state = ConfirmDialogState::new(1, "Title", "Delete all files?");
+//! state.with_listener(Some(tx));
+//!
+//! let run = run_script(&mut state, "Right Left Right").unwrap();
+//! assert_eq!(run.outcome, None);
+//!
+//! let screen = render_dialog(ConfirmDialog::default(), &mut state, 60, 12);
+//! assert!(screen.contains("Delete all files?"));
+//!
+//! let run = run_script(&mut state, "Enter").unwrap();
+//! assert_eq!(run.outcome, Some(DialogOutcome::No));
+//! assert_eq!(run.messages, vec![(1, Some(false))]);
+//! assert_eq!(rx.try_recv(), Ok((1, Some(false))));
+//! ```
+use std::fmt;
+#[cfg(feature = "crossterm")]
+use std::sync::mpsc::channel;
+
+#[cfg(feature = "crossterm")]
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{ConfirmDialog, ConfirmDialogState, DialogOutcome, Listener, PopupMessage};
+
+/// VHS commands that do not send keys
+#[cfg(feature = "crossterm")]
+const IGNORED_COMMANDS: [&str; 9] = [
+    "Output",
+    "Set",
+    "Require",
+    "Source",
+    "Env",
+    "Wait",
+    "Screenshot",
+    "Hide",
+    "Show",
+];
+
+/// An error in a key script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptError {
+    /// The 1-based line of the error
+    pub line: usize,
+    /// What went wrong
+    pub message: String,
+}
+
+/// The result of [`run_script`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptRun {
+    /// How the dialog was closed, `None` if it is still opened
+    pub outcome: Option<DialogOutcome>,
+    /// The messages sent to the listener while the script ran
+    pub messages: Vec<Listener>,
+    /// The number of keys handled before the dialog closed
+    pub keys: usize,
+}
+
+/// Parse a key script into key press events
+#[cfg(feature = "crossterm")]
+pub fn parse_script(script: &str) -> Result<Vec<KeyEvent>, ScriptError> {
+    let mut events = Vec::new();
+
+    for (index, line) in script.lines().enumerate() {
+        let error = |message: String| ScriptError {
+            line: index + 1,
+            message,
+        };
+        let mut tokens = tokenize(line).map_err(&error)?.into_iter().peekable();
+
+        while let Some(token) = tokens.next() {
+            // `Enter@100ms` sets the typing speed, which does not matter here
+            let command = token.split('@').next().unwrap_or_default();
+
+            if IGNORED_COMMANDS.contains(&command) {
+                if !matches!(command, "Hide" | "Show") {
+                    break;
+                }
+                continue;
+            }
+
+            if command == "Sleep" {
+                tokens.next_if(|arg| arg.starts_with(|chr: char| chr.is_ascii_digit()));
+                continue;
+            }
+
+            if command == "Type" {
+                let text = tokens
+                    .next()
+                    .filter(|arg| arg.starts_with(['"', '\'', '`']))
+                    .ok_or_else(|| error("`Type` expects a quoted string".to_string()))?;
+                events.extend(
+                    text[1..text.len() - 1]
+                        .chars()
+                        .map(|chr| KeyEvent::new(KeyCode::Char(chr), KeyModifiers::NONE)),
+                );
+                continue;
+            }
+
+            let event =
+                parse_key(command).ok_or_else(|| error(format!("unknown command `{command}`")))?;
+            let count = match tokens.next_if(|arg| arg.chars().all(|chr| chr.is_ascii_digit())) {
+                Some(count) => count
+                    .parse::<usize>()
+                    .map_err(|err| error(format!("invalid repeat count `{count}`: {err}")))?,
+                None => 1,
+            };
+            events.extend(std::iter::repeat_n(event, count));
+        }
+    }
+
+    Ok(events)
+}
+
+/// Open `state` if needed and feed it the keys of `script` until the dialog closes.
+/// The messages sent to the listener are collected and still forwarded to it.
+#[cfg(feature = "crossterm")]
+pub fn run_script(state: &mut ConfirmDialogState, script: &str) -> Result<ScriptRun, ScriptError> {
+    let events = parse_script(script)?;
+
+    let (tx, rx) = channel();
+    let listener = state.listener.replace(tx);
+
+    if !state.is_opened() {
+        state.open();
+    }
+
+    let mut keys = 0;
+    for event in &events {
+        if !state.is_opened() {
+            break;
+        }
+        state.handle(event);
+        keys += 1;
+    }
+
+    state.listener = listener;
+    let messages = rx.try_iter().collect::<Vec<_>>();
+    if let Some(listener) = state.listener.as_ref() {
+        for message in &messages {
+            let _ = listener.send(*message);
+        }
+    }
+
+    Ok(ScriptRun {
+        outcome: state.outcome(),
+        messages,
+        keys,
+    })
+}
+
+/// Render `dialog` on a `width` x `height` buffer
+pub fn render_dialog_buffer(
+    dialog: ConfirmDialog,
+    state: &mut ConfirmDialogState,
+    width: u16,
+    height: u16,
+) -> Buffer {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+    dialog.render(buffer.area, &mut buffer, state);
+    buffer
+}
+
+/// Render `dialog` on a `width` x `height` buffer and return its text, see [`buffer_to_string`]
+pub fn render_dialog(
+    dialog: ConfirmDialog,
+    state: &mut ConfirmDialogState,
+    width: u16,
+    height: u16,
+) -> String {
+    buffer_to_string(&render_dialog_buffer(dialog, state, width, height))
+}
+
+/// Render `popup` on a `width` x `height` buffer and return its text, see [`buffer_to_string`]
+pub fn render_popup(popup: PopupMessage, width: u16, height: u16) -> String {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+    popup.render(buffer.area, &mut buffer);
+    buffer_to_string(&buffer)
+}
+
+/// The symbols of `buffer`, one line per row with the trailing spaces removed
+pub fn buffer_to_string(buffer: &Buffer) -> String {
+    let mut output = String::new();
+    for y in buffer.area.top()..buffer.area.bottom() {
+        let mut line = String::new();
+        let mut skip = 0usize;
+        for x in buffer.area.left()..buffer.area.right() {
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let symbol = crate::hyperlink::strip_hyperlink(buffer[(x, y)].symbol());
+            line.push_str(&symbol);
+            skip = symbol.width().saturating_sub(1);
+        }
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(feature = "crossterm")]
+fn parse_key(name: &str) -> Option<KeyEvent> {
+    if let Some(key) = name.strip_prefix("Ctrl+") {
+        let mut chars = key.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(chr), None) => Some(KeyEvent::new(
+                KeyCode::Char(chr.to_ascii_lowercase()),
+                KeyModifiers::CONTROL,
+            )),
+            _ => None,
+        };
+    }
+
+    let code = match name {
+        "Enter" => KeyCode::Enter,
+        "Escape" | "Esc" => KeyCode::Esc,
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Tab" => KeyCode::Tab,
+        "Space" => KeyCode::Char(' '),
+        "Backspace" => KeyCode::Backspace,
+        "Delete" => KeyCode::Delete,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        _ => return None,
+    };
+    Some(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+/// Split a line on whitespace, keeping quoted strings (with their quotes) as one token
+/// and dropping `#` comments
+#[cfg(feature = "crossterm")]
+fn tokenize(line: &str) -> Result<Vec<&str>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = line.trim_start();
+
+    while !rest.is_empty() && !rest.starts_with('#') {
+        let end = match rest.chars().next() {
+            Some(quote @ ('"' | '\'' | '`')) => {
+                rest[1..]
+                    .find(quote)
+                    .ok_or_else(|| format!("unterminated string {rest}"))?
+                    + 2
+            }
+            _ => rest.find(char::is_whitespace).unwrap_or(rest.len()),
+        };
+        tokens.push(&rest[..end]);
+        rest = rest[end..].trim_start();
+    }
+
+    Ok(tokens)
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ScriptError {}
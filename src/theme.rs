@@ -0,0 +1,369 @@
+use ratatui_core::layout::Alignment;
+use ratatui_core::style::Style;
+use ratatui_widgets::block::Padding;
+
+use crate::layout::DEFAULT_BUTTON_GAP;
+use crate::{ConfirmDialog, PopupMessage, Theme};
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            bg: Default::default(),
+            borders: Default::default(),
+            border_type: Default::default(),
+            border_style: Default::default(),
+            button_style: Style::new(),
+            selected_button_style: Style::new().underlined(),
+            text_style: Style::new().white(),
+            padding: Padding::uniform(2),
+            text_alignment: Alignment::Left,
+            title_alignment: Alignment::Center,
+            severities: Default::default(),
+            mnemonic_display: Default::default(),
+            mnemonic_style: Style::new().underlined(),
+            button_order: Default::default(),
+            destructive_button_style: Style::new().red(),
+            disabled_button_style: Style::new().dark_gray(),
+            button_alignment: Default::default(),
+            equal_button_width: false,
+            button_gap: DEFAULT_BUTTON_GAP,
+            stacked_buttons: false,
+            key_hints: false,
+            key_hint_style: Style::new().dark_gray(),
+        }
+    }
+}
+
+impl ConfirmDialog {
+    /// Apply all the colors, borders, padding, alignments and severity styles of `theme`
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.bg = theme.bg;
+        self.borders = theme.borders;
+        self.border_type = theme.border_type;
+        self.border_style = theme.border_style;
+        self.button_style = theme.button_style;
+        self.selected_button_style = theme.selected_button_style;
+        self.text_style = theme.text_style;
+        self.padding = theme.padding;
+        self.text_alignment = theme.text_alignment;
+        self.title_alignment = theme.title_alignment;
+        self.severities = theme.severities.clone();
+        self.mnemonic_display = theme.mnemonic_display;
+        self.mnemonic_style = theme.mnemonic_style;
+        self.button_order = theme.button_order;
+        self.destructive_button_style = theme.destructive_button_style;
+        self.disabled_button_style = theme.disabled_button_style;
+        self.button_alignment = theme.button_alignment;
+        self.equal_button_width = theme.equal_button_width;
+        self.button_gap = theme.button_gap;
+        self.stacked_buttons = theme.stacked_buttons;
+        self.key_hints = theme.key_hints;
+        self.key_hint_style = theme.key_hint_style;
+        self
+    }
+}
+
+impl PopupMessage<'_, '_> {
+    /// Apply the colors, borders, padding, alignments and severity styles of `theme`.
+    /// Button styles are ignored since the popup has no buttons.
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        self.bg = theme.bg;
+        self.borders = theme.borders;
+        self.border_type = theme.border_type;
+        self.border_style = theme.border_style;
+        self.text_style = theme.text_style;
+        self.padding = theme.padding;
+        self.text_alignment = theme.text_alignment;
+        self.title_alignment = theme.title_alignment;
+        self.severities = theme.severities.clone();
+        self
+    }
+}
+
+impl From<&Theme> for ConfirmDialog {
+    fn from(theme: &Theme) -> Self {
+        ConfirmDialog::new().theme(theme)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Theme {
+    /// Parse a theme from a TOML document
+    pub fn from_toml_str(value: &str) -> Result<Self, crate::ThemeError> {
+        toml::from_str(value).map_err(crate::ThemeError::Toml)
+    }
+
+    /// Parse a theme from a JSON document
+    pub fn from_json_str(value: &str) -> Result<Self, crate::ThemeError> {
+        serde_json::from_str(value).map_err(crate::ThemeError::Json)
+    }
+
+    /// Load a theme from a `.toml` or `.json` file, the format is picked from the file extension
+    pub fn from_file<P>(path: P) -> Result<Self, crate::ThemeError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+
+        match extension.as_deref() {
+            Some("toml") => Self::from_toml_str(&std::fs::read_to_string(path)?),
+            Some("json") => Self::from_json_str(&std::fs::read_to_string(path)?),
+            _ => Err(crate::ThemeError::UnsupportedFormat(path.to_path_buf())),
+        }
+    }
+
+    /// Serialize the theme as a TOML document
+    pub fn to_toml_string(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Serialize the theme as a JSON document
+    pub fn to_json_string(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<std::io::Error> for crate::ThemeError {
+    fn from(value: std::io::Error) -> Self {
+        crate::ThemeError::Io(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::fmt::Display for crate::ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            crate::ThemeError::Io(err) => write!(f, "could not read theme: {err}"),
+            crate::ThemeError::Toml(err) => write!(f, "invalid TOML theme: {err}"),
+            crate::ThemeError::Json(err) => write!(f, "invalid JSON theme: {err}"),
+            crate::ThemeError::UnsupportedFormat(path) => write!(
+                f,
+                "unsupported theme format for {}, expected a .toml or .json file",
+                path.display()
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for crate::ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            crate::ThemeError::Io(err) => Some(err),
+            crate::ThemeError::Toml(err) => Some(err),
+            crate::ThemeError::Json(err) => Some(err),
+            crate::ThemeError::UnsupportedFormat(_) => None,
+        }
+    }
+}
+
+/// Serde codec for [`Color`] with an error message listing the accepted formats
+#[cfg(feature = "serde")]
+pub(crate) mod color {
+    use std::str::FromStr;
+
+    use ratatui_core::style::Color;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn parse(value: &str) -> Result<Color, String> {
+        Color::from_str(value).map_err(|_| {
+            format!(
+                "invalid color `{value}`, expected a color name (e.g. `red`, `light-blue`, `dark-gray`), \
+                 a hex value (e.g. `#ff8800`) or an index between 0 and 255"
+            )
+        })
+    }
+
+    pub(crate) fn serialize<S>(color: &Color, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(color)
+    }
+
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        parse(&value).map_err(serde::de::Error::custom)
+    }
+
+    pub(crate) mod option {
+        use ratatui_core::style::Color;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub(crate) fn serialize<S>(color: &Option<Color>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match color {
+                Some(color) => serializer.collect_str(color),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<Color>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<String>::deserialize(deserializer)?
+                .map(|value| super::parse(&value).map_err(serde::de::Error::custom))
+                .transpose()
+        }
+    }
+}
+
+/// Serde codec for button labels, checked with [`ButtonLabel::from`](crate::ButtonLabel::from)
+/// so a broken label is reported when the theme is loaded
+#[cfg(feature = "serde")]
+pub(crate) mod label {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::ButtonLabel;
+
+    pub(crate) fn parse(value: String) -> Result<String, String> {
+        ButtonLabel::from(value.as_str())
+            .map(|_| value)
+            .map_err(|err| err.to_string())
+    }
+
+    pub(crate) fn serialize<S>(label: &str, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(label)
+    }
+
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        parse(String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+
+    pub(crate) mod option {
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        pub(crate) fn serialize<S>(label: &Option<String>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            label.serialize(serializer)
+        }
+
+        pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<String>::deserialize(deserializer)?
+                .map(|value| super::parse(value).map_err(serde::de::Error::custom))
+                .transpose()
+        }
+    }
+}
+
+/// Serde codec for [`Padding`](ratatui_widgets::block::Padding), rejecting sides wider than
+/// [`MAX_PADDING`](padding::MAX_PADDING) when the theme is loaded
+#[cfg(feature = "serde")]
+pub(crate) mod padding {
+    use ratatui_widgets::block::Padding;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// The widest padding side a theme may set
+    pub(crate) const MAX_PADDING: u16 = 255;
+
+    pub(crate) fn serialize<S>(padding: &Padding, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        padding.serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Padding, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let padding = Padding::deserialize(deserializer)?;
+        let widest = [padding.left, padding.right, padding.top, padding.bottom]
+            .into_iter()
+            .max()
+            .unwrap_or(0);
+        if widest > MAX_PADDING {
+            return Err(serde::de::Error::custom(format!(
+                "padding {widest} is wider than {MAX_PADDING}"
+            )));
+        }
+        Ok(padding)
+    }
+}
+
+/// Serde codec for [`Style`] as `{ fg = "...", bg = "...", modifiers = ["bold", ...] }`
+#[cfg(feature = "serde")]
+pub(crate) mod style {
+    use ratatui_core::style::{Color, Modifier, Style};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize, Default)]
+    #[serde(default, deny_unknown_fields)]
+    struct StyleDef {
+        #[serde(with = "super::color::option", skip_serializing_if = "Option::is_none")]
+        fg: Option<Color>,
+        #[serde(with = "super::color::option", skip_serializing_if = "Option::is_none")]
+        bg: Option<Color>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        modifiers: Vec<String>,
+    }
+
+    pub(crate) fn parse_modifier(value: &str) -> Result<Modifier, String> {
+        let name = value.trim().to_ascii_uppercase().replace(['-', ' '], "_");
+        Modifier::from_name(&name).ok_or_else(|| {
+            let names = Modifier::all()
+                .iter_names()
+                .map(|(name, _)| name.to_ascii_lowercase())
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("invalid modifier `{value}`, expected one of: {names}")
+        })
+    }
+
+    pub(crate) fn serialize<S>(style: &Style, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        StyleDef {
+            fg: style.fg,
+            bg: style.bg,
+            modifiers: style
+                .add_modifier
+                .iter_names()
+                .map(|(name, _)| name.to_ascii_lowercase())
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Style, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let def = StyleDef::deserialize(deserializer)?;
+        let mut style = Style::new();
+        if let Some(fg) = def.fg {
+            style = style.fg(fg);
+        }
+        if let Some(bg) = def.bg {
+            style = style.bg(bg);
+        }
+        for modifier in def.modifiers {
+            style =
+                style.add_modifier(parse_modifier(&modifier).map_err(serde::de::Error::custom)?);
+        }
+        Ok(style)
+    }
+}